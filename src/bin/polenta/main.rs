@@ -0,0 +1,42 @@
+mod repl;
+mod run;
+
+use std::process::ExitCode;
+
+const USAGE: &str = r#"Usage:
+  polenta                         start the interactive REPL
+  polenta run [options] <file>    interpret a script, use - to read from stdin
+
+Options:
+  --field <name>   field to work over, defaults to goldilocks
+  --quiet          do not print the results
+  --json           print the results or the error as JSON"#;
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match args.first().map(String::as_str) {
+        None => match repl::repl() {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{:?}", err);
+                ExitCode::FAILURE
+            }
+        },
+        Some("run") => match run::RunOptions::parse(&args[1..]) {
+            Ok(options) => run::run(options),
+            Err(message) => {
+                eprintln!("{}\n\n{}", message, USAGE);
+                ExitCode::from(2)
+            }
+        },
+        Some("help" | "-h" | "--help") => {
+            println!("{}", USAGE);
+            ExitCode::SUCCESS
+        }
+        Some(arg) => {
+            eprintln!("Unknown command: {}\n\n{}", arg, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -1,14 +1,13 @@
 use colored::Colorize;
 use miette::{IntoDiagnostic, Report, Result};
-use polenta::{Polenta, PolentaUtilExt};
+use polenta::{AnyPolenta, PolentaField};
 use rustyline::error::ReadlineError;
 use rustyline::DefaultEditor;
 
-type F = lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
-
 const CMD_HELP: &str = "help";
 const CMD_EXIT: &str = "exit";
 const CMD_RESET: &str = "reset";
+const CMD_FIELD: &str = "field";
 
 const WELCOME_BANNER: &str = r#"
               _            _
@@ -19,7 +18,8 @@ const WELCOME_BANNER: &str = r#"
  |_|
 "#;
 
-fn main() -> Result<()> {
+/// Runs the interactive REPL until the user exits.
+pub fn repl() -> Result<()> {
     println!(
         "{}\n(v{})",
         WELCOME_BANNER.green().bold(),
@@ -30,7 +30,7 @@ fn main() -> Result<()> {
         CMD_EXIT.yellow(),
         CMD_HELP.yellow()
     );
-    let mut polenta = Polenta::<F>::new();
+    let mut polenta = AnyPolenta::default();
     let mut rl = DefaultEditor::new().into_diagnostic()?;
 
     let prompt_line = format!("{}", "> ".green());
@@ -47,19 +47,45 @@ fn main() -> Result<()> {
                     println!("{:<7}show this help message", CMD_HELP.yellow());
                     println!("{:<7}exit the program", CMD_EXIT.yellow());
                     println!("{:<7}reset symbols", CMD_RESET.yellow());
+                    println!(
+                        "{:<7}show the current field, or switch to another one with {} <name>",
+                        CMD_FIELD.yellow(),
+                        CMD_FIELD.yellow()
+                    );
+                    println!(
+                        "{:<7}available fields are: {}",
+                        "",
+                        PolentaField::ALL.map(|field| field.name()).join(", ")
+                    );
                 }
                 CMD_EXIT => {
                     println!("bye!");
                     break;
                 }
                 CMD_RESET => {
-                    polenta = Polenta::<F>::new();
+                    polenta = AnyPolenta::new(polenta.field());
                     println!("Symbol table reset.");
                 }
-                // TODO:!!!
-                "field" => {
-                    println!("Order: {}", F::ORDER);
-                    // todo!("change underlying field if arg is given, otherwise print name");
+                CMD_FIELD => {
+                    println!("Field: {}", polenta.field().name().yellow());
+                    println!("Order: {}", polenta.order());
+                }
+                cmd if cmd.starts_with(&format!("{} ", CMD_FIELD)) => {
+                    let _ = rl.add_history_entry(cmd);
+
+                    let name = cmd[CMD_FIELD.len()..].trim();
+                    match AnyPolenta::from_field_name(name) {
+                        Ok(new_polenta) => {
+                            polenta = new_polenta;
+                            println!(
+                                "Switched to {}, symbol table reset.",
+                                polenta.field().name().yellow()
+                            );
+                        }
+                        Err(e) => {
+                            println!("{:?}", Report::from(e));
+                        }
+                    }
                 }
 
                 _ => {
@@ -72,7 +98,9 @@ fn main() -> Result<()> {
                     let result = polenta.interpret(input);
                     match result {
                         Ok(polys) => {
-                            println!("{}", Polenta::poly_print(polys.last().unwrap()).blue());
+                            if let Some(poly) = polys.last() {
+                                println!("{}", poly.blue());
+                            }
                         }
                         Err(e) => {
                             println!("{:?}", Report::from(e));
@@ -0,0 +1,130 @@
+use miette::{Diagnostic, Report};
+use polenta::{AnyPolenta, PolentaError, PolentaField};
+use serde_json::json;
+use std::{
+    io::{self, Read},
+    process::ExitCode,
+};
+
+/// Options of the `run` command.
+pub struct RunOptions {
+    /// Path to the script, or `-` for stdin.
+    path: String,
+    field: PolentaField,
+    quiet: bool,
+    json: bool,
+}
+
+impl RunOptions {
+    /// Parses the arguments that come after `run`.
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut path = None;
+        let mut field = PolentaField::default();
+        let mut quiet = false;
+        let mut json = false;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--field" => {
+                    let name = args.next().ok_or("Missing field name after --field")?;
+                    field = name.parse().map_err(|_| {
+                        format!(
+                            "Unknown field: {}, expected one of: {}",
+                            name,
+                            PolentaField::ALL.map(|field| field.name()).join(", ")
+                        )
+                    })?;
+                }
+                "--quiet" => quiet = true,
+                "--json" => json = true,
+                flag if flag.starts_with("--") => return Err(format!("Unknown option: {}", flag)),
+                _ if path.is_some() => return Err("Expected a single script".to_string()),
+                _ => path = Some(arg.clone()),
+            }
+        }
+
+        Ok(Self {
+            path: path.unwrap_or("-".to_string()),
+            field,
+            quiet,
+            json,
+        })
+    }
+}
+
+/// Interprets a whole script and prints the results of its expression statements.
+///
+/// Exits with 1 if the script fails, e.g. due to a failing assertion, and with 2 if the script
+/// could not be read.
+pub fn run(options: RunOptions) -> ExitCode {
+    let (name, input) = match read_script(&options.path) {
+        Ok(script) => script,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", options.path, err);
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut polenta = AnyPolenta::new(options.field);
+    match polenta.run(&name, &input) {
+        Ok(results) => {
+            if options.quiet {
+                // print nothing
+            } else if options.json {
+                let output = json!({ "field": options.field.name(), "results": results });
+                println!("{}", output);
+            } else {
+                for result in results {
+                    println!("{}", result);
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            if options.json {
+                println!("{}", error_to_json(&err, options.field));
+            } else {
+                eprintln!("{:?}", Report::from(err));
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads the script at the given path, or from stdin if the path is `-`, and returns its name
+/// along with its contents.
+fn read_script(path: &str) -> io::Result<(String, String)> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(("stdin".to_string(), input))
+    } else {
+        Ok((path.to_string(), std::fs::read_to_string(path)?))
+    }
+}
+
+/// Converts an error to JSON, with its location in the script if there is one.
+fn error_to_json(err: &PolentaError, field: PolentaField) -> serde_json::Value {
+    let location = err
+        .labels()
+        .and_then(|mut labels| labels.next())
+        .zip(err.source_code())
+        .and_then(|(label, src)| src.read_span(label.inner(), 0, 0).ok())
+        .map(|contents| {
+            json!({
+                "source": contents.name(),
+                "line": contents.line() + 1,
+                "column": contents.column() + 1,
+            })
+        });
+
+    json!({
+        "field": field.name(),
+        "error": {
+            "message": err.to_string(),
+            "help": err.help().map(|help| help.to_string()),
+            "location": location,
+        },
+    })
+}
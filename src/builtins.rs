@@ -0,0 +1,603 @@
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsPrimeField},
+    polynomial::Polynomial,
+};
+
+use crate::{
+    errors::InterpreterError,
+    extension::{ExtensionElement, MAX_EXTENSION_DEGREE},
+    factor, felt,
+    kzg::to_kzg_felt,
+    ntt,
+    program::{checked_index, Value},
+    utils::felt_to_u64,
+    Polenta, PolentaUtilExt,
+};
+
+/// Names of the builtin functions.
+///
+/// User-defined functions take precedence over builtins with the same name.
+const BUILTINS: &[&str] = &[
+    "verify",
+    "fri_verify",
+    "interpolate",
+    "deriv",
+    "compose",
+    "gcd",
+    "xgcd",
+    "invmod",
+    "divmod",
+    "roots",
+    "factor",
+    "domain",
+    "fft",
+    "ifft",
+    "vanishing",
+    "lagrange_basis",
+    "len",
+    "range",
+    "inv",
+    "sqrt",
+    "legendre",
+    "order",
+    "generator",
+    "root_of_unity",
+    "ext",
+    "components",
+];
+
+impl<F: IsPrimeField> Polenta<F> {
+    /// Returns true if there is a builtin function with the given name.
+    pub(crate) fn is_builtin(name: &str) -> bool {
+        BUILTINS.contains(&name)
+    }
+
+    /// Calls the builtin function with the given name, over the evaluated arguments.
+    pub(crate) fn call_builtin(
+        &mut self,
+        name: &str,
+        args: Vec<Value<F>>,
+    ) -> Result<Value<F>, InterpreterError> {
+        match name {
+            "verify" => self.builtin_verify(expect_args(name, args)?),
+            "fri_verify" => Self::builtin_fri_verify(expect_args(name, args)?),
+            "interpolate" => Self::builtin_interpolate(expect_args(name, args)?),
+            "deriv" if args.len() == 1 => {
+                let [poly] = expect_args(name, args)?;
+                Self::builtin_deriv([poly, Self::felt_as_poly(FieldElement::one()).into()])
+            }
+            "deriv" => Self::builtin_deriv(expect_args(name, args)?),
+            "compose" => self.builtin_compose(expect_args(name, args)?),
+            "gcd" => Self::builtin_gcd(expect_args(name, args)?),
+            "xgcd" => Self::builtin_xgcd(expect_args(name, args)?),
+            "invmod" => Self::builtin_invmod(expect_args(name, args)?),
+            "divmod" => Self::builtin_divmod(expect_args(name, args)?),
+            "roots" => self.builtin_roots(expect_args(name, args)?),
+            "factor" => self.builtin_factor(expect_args(name, args)?),
+            "domain" => self.builtin_domain(expect_args(name, args)?),
+            "fft" => self.builtin_fft(expect_args(name, args)?),
+            "ifft" => self.builtin_ifft(expect_args(name, args)?),
+            "vanishing" => self.builtin_vanishing(expect_args(name, args)?),
+            "lagrange_basis" => self.builtin_lagrange_basis(expect_args(name, args)?),
+            "len" => Self::builtin_len(expect_args(name, args)?),
+            "range" => self.builtin_range(expect_args(name, args)?),
+            "inv" => Self::builtin_inv(expect_args(name, args)?),
+            "sqrt" => Self::builtin_sqrt(expect_args(name, args)?),
+            "legendre" => Self::builtin_legendre(expect_args(name, args)?),
+            "order" => Self::builtin_order(expect_args(name, args)?),
+            "generator" => Self::builtin_generator(expect_args(name, args)?),
+            "root_of_unity" => Self::builtin_root_of_unity(expect_args(name, args)?),
+            "ext" => self.builtin_ext(expect_args(name, args)?),
+            "components" => Self::builtin_components(expect_args(name, args)?),
+            _ => Err(InterpreterError::UnknownFunction(name.to_string())),
+        }
+    }
+
+    /// `verify(C, z, y, proof)` returns 1 if the proof shows that the polynomial committed with
+    /// `C` evaluates to `y` at `z`, and 0 otherwise.
+    fn builtin_verify(&mut self, args: [Value<F>; 4]) -> Result<Value<F>, InterpreterError> {
+        let [commitment, z, y, proof] = args;
+        let Value::Commitment(commitment) = commitment else {
+            return Err(InterpreterError::TypeError(format!(
+                "Expected a commitment, found {}",
+                commitment
+            )));
+        };
+        let Value::Proof(proof) = proof else {
+            return Err(InterpreterError::TypeError(format!(
+                "Expected a proof, found {}",
+                proof
+            )));
+        };
+        let (z, y) = (
+            to_kzg_felt(&z.expect_felt()?),
+            to_kzg_felt(&y.expect_felt()?),
+        );
+
+        let is_valid = self.kzg()?.verify(&z, &y, &commitment, &proof);
+        Ok(Self::poly_from_bool(is_valid).into())
+    }
+
+    /// `fri_verify(C)` returns 1 if the FRI proof `C` is valid, and 0 otherwise.
+    fn builtin_fri_verify(args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [proof] = args;
+        let Value::Fri(proof) = proof else {
+            return Err(InterpreterError::TypeError(format!(
+                "Expected a FRI proof, found {}",
+                proof
+            )));
+        };
+
+        Ok(Self::poly_from_bool(proof.verify()).into())
+    }
+
+    /// `interpolate([(x0, y0), ..., (xn, yn)])` returns the polynomial with the least degree that
+    /// passes through the given points.
+    fn builtin_interpolate(args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [points] = args;
+        let (xs, ys): (Vec<_>, Vec<_>) = points
+            .expect_list()?
+            .into_iter()
+            .map(|point| {
+                let [x, y] = point.expect_tuple()?;
+                Ok((x.expect_felt()?, y.expect_felt()?))
+            })
+            .collect::<Result<Vec<_>, InterpreterError>>()?
+            .into_iter()
+            .unzip();
+
+        expect_distinct(&xs)?;
+
+        let poly = Polynomial::interpolate(&xs, &ys).expect("expected distinct x-coordinates");
+        Ok(poly.into())
+    }
+
+    /// `deriv(P)` returns the formal derivative of `P`, and `deriv(P, k)` returns its `k`-th
+    /// derivative.
+    fn builtin_deriv(args: [Value<F>; 2]) -> Result<Value<F>, InterpreterError> {
+        let [poly, k] = args;
+        let mut poly = poly.expect_poly()?;
+
+        // derivatives beyond the degree are all zero
+        let k = felt_to_u64(&k.expect_felt()?).unwrap_or(u64::MAX);
+        for _ in 0..k.min(poly.coeff_len() as u64) {
+            poly = Self::poly_derivative(&poly);
+        }
+        Ok(poly.into())
+    }
+
+    /// `compose(P, Q)` returns the polynomial `P(Q(x))`, same as `P@Q`.
+    fn builtin_compose(&self, args: [Value<F>; 2]) -> Result<Value<F>, InterpreterError> {
+        let [poly, inner] = args;
+        let (poly, inner) = (poly.expect_poly()?, inner.expect_poly()?);
+        self.check_degree(poly.degree() as u64 * inner.degree() as u64)?;
+        Ok(Self::poly_compose(&poly, &inner).into())
+    }
+
+    /// `gcd(P, Q)` returns the monic greatest common divisor of `P` and `Q`.
+    fn builtin_gcd(args: [Value<F>; 2]) -> Result<Value<F>, InterpreterError> {
+        let [a, b] = args;
+        let (gcd, _, _) = Self::poly_xgcd(&a.expect_poly()?, &b.expect_poly()?);
+        Ok(gcd.into())
+    }
+
+    /// `xgcd(P, Q)` returns a tuple `(G, S, T)` where `G` is the monic greatest common divisor of
+    /// `P` and `Q`, and `S*P + T*Q = G`.
+    fn builtin_xgcd(args: [Value<F>; 2]) -> Result<Value<F>, InterpreterError> {
+        let [a, b] = args;
+        let (gcd, s, t) = Self::poly_xgcd(&a.expect_poly()?, &b.expect_poly()?);
+        Ok(Value::Tuple(vec![gcd.into(), s.into(), t.into()]))
+    }
+
+    /// `invmod(P, M)` returns the inverse of `P` modulo `M`, i.e. the polynomial `S` with a degree
+    /// less than that of `M` such that `S*P = 1` in `F[x]/(M)`.
+    fn builtin_invmod(args: [Value<F>; 2]) -> Result<Value<F>, InterpreterError> {
+        let [poly, modulus] = args;
+        Ok(Self::invmod(&poly.expect_poly()?, &modulus.expect_poly()?)?.into())
+    }
+
+    /// Returns the inverse of `poly` modulo `modulus`, see [`Self::builtin_invmod`].
+    pub(crate) fn invmod(
+        poly: &Polynomial<FieldElement<F>>,
+        modulus: &Polynomial<FieldElement<F>>,
+    ) -> Result<Polynomial<FieldElement<F>>, InterpreterError> {
+        if Self::poly_is_zero(modulus) {
+            return Err(InterpreterError::DivisionByZero);
+        }
+
+        let (gcd, s, _) = Self::poly_xgcd(poly, modulus);
+        if gcd != Polynomial::new_monomial(FieldElement::one(), 0) {
+            return Err(InterpreterError::NotInvertible(format!(
+                "{} modulo {}",
+                Self::poly_print(poly),
+                Self::poly_print(modulus)
+            )));
+        }
+
+        let (_, inverse) = s.long_division_with_remainder(modulus);
+        Ok(inverse)
+    }
+
+    /// `divmod(P, Q)` returns a tuple `(D, R)` of the quotient and the remainder of dividing `P`
+    /// by `Q`, i.e. `P = D*Q + R` where `R` has a degree less than that of `Q`.
+    fn builtin_divmod(args: [Value<F>; 2]) -> Result<Value<F>, InterpreterError> {
+        let [a, b] = args;
+        let (a, b) = (a.expect_poly()?, b.expect_poly()?);
+        if Self::poly_is_zero(&b) {
+            return Err(InterpreterError::DivisionByZero);
+        }
+
+        let (quotient, remainder) = a.long_division_with_remainder(&b);
+        Ok(Value::Tuple(vec![quotient.into(), remainder.into()]))
+    }
+
+    /// `roots(P)` returns the roots of `P` within the field as a list of `(root, multiplicity)`
+    /// tuples.
+    fn builtin_roots(&self, args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [poly] = args;
+        let poly = expect_non_zero(poly.expect_poly()?)?;
+        self.check_factor_degree(poly.degree())?;
+        let roots = factor::roots(&poly)?
+            .into_iter()
+            .map(|(root, multiplicity)| {
+                Ok(Value::Tuple(vec![
+                    Self::felt_as_poly(root).into(),
+                    Self::felt_as_poly(multiplicity_as_felt(multiplicity)?).into(),
+                ]))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Value::List(roots))
+    }
+
+    /// `factor(P)` returns the monic irreducible factors of `P` as a list of
+    /// `(factor, multiplicity)` tuples, omitting the leading coefficient of `P`.
+    fn builtin_factor(&self, args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [poly] = args;
+        let poly = expect_non_zero(poly.expect_poly()?)?;
+        self.check_factor_degree(poly.degree())?;
+        let factors = factor::factor(&poly)?
+            .into_iter()
+            .map(|(factor, multiplicity)| {
+                Ok(Value::Tuple(vec![
+                    factor.into(),
+                    Self::felt_as_poly(multiplicity_as_felt(multiplicity)?).into(),
+                ]))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Value::List(factors))
+    }
+
+    /// `domain(n)` returns the multiplicative subgroup of order `n` as the list `[1, w, ..., w^(n-1)]`
+    /// for a primitive `n`-th root of unity `w`.
+    fn builtin_domain(&self, args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [order] = args;
+        let order = self.check_domain_order(felt_to_u64(&order.expect_felt()?))?;
+        let domain = ntt::domain::<F>(order).ok_or_else(|| no_domain_error(order))?;
+        Ok(Value::List(
+            domain
+                .into_iter()
+                .map(|x| Self::felt_as_poly(x).into())
+                .collect(),
+        ))
+    }
+
+    /// `fft(P, n)` returns the evaluations of `P` over `domain(n)`.
+    fn builtin_fft(&self, args: [Value<F>; 2]) -> Result<Value<F>, InterpreterError> {
+        let [poly, order] = args;
+        let poly = poly.expect_poly()?;
+        let order = self.check_domain_order(felt_to_u64(&order.expect_felt()?))?;
+        let evals =
+            ntt::evaluate_over_domain(&poly, order).ok_or_else(|| no_domain_error(order))?;
+        Ok(Value::List(
+            evals
+                .into_iter()
+                .map(|y| Self::felt_as_poly(y).into())
+                .collect(),
+        ))
+    }
+
+    /// `ifft(evals)` returns the polynomial with the given evaluations over `domain(n)`, where `n`
+    /// is the number of evaluations.
+    fn builtin_ifft(&self, args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [evals] = args;
+        let evals = evals
+            .expect_list()?
+            .into_iter()
+            .map(|y| y.expect_felt())
+            .collect::<Result<Vec<_>, _>>()?;
+        let order = self.check_domain_order(Some(evals.len() as u64))?;
+        let poly = ntt::interpolate_over_domain(&evals).ok_or_else(|| no_domain_error(order))?;
+        Ok(poly.into())
+    }
+
+    /// `vanishing(n)` returns `x^n - 1`, which vanishes over `domain(n)`, and `vanishing(points)`
+    /// returns the monic polynomial that vanishes exactly over the given points.
+    fn builtin_vanishing(&self, args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [arg] = args;
+        if let Value::List(points) = arg {
+            let points = points
+                .into_iter()
+                .map(|point| point.expect_felt())
+                .collect::<Result<Vec<_>, _>>()?;
+            self.check_degree(points.len() as u64)?;
+            return Ok(vanishing_over(&points).into());
+        }
+
+        // unlike the other domain functions, the result is a polynomial of degree `n`
+        let order = felt_to_u64(&arg.expect_felt()?).unwrap_or(u64::MAX);
+        self.check_degree(order)?;
+        let order = expect_domain_order(order)?;
+        if ntt::root_of_unity::<F>(order).is_none() {
+            return Err(no_domain_error(order));
+        }
+        let x_n = Polynomial::new_monomial(FieldElement::one(), order);
+        Ok((x_n - FieldElement::<F>::one()).into())
+    }
+
+    /// `lagrange_basis(i, points)` returns the `i`-th Lagrange polynomial over the given points,
+    /// which is 1 at the `i`-th point and 0 at the others.
+    fn builtin_lagrange_basis(&self, args: [Value<F>; 2]) -> Result<Value<F>, InterpreterError> {
+        let [index, points] = args;
+        let index = index.expect_felt()?;
+        let points = points
+            .expect_list()?
+            .into_iter()
+            .map(|point| point.expect_felt())
+            .collect::<Result<Vec<_>, _>>()?;
+        let i = checked_index(&index, points.len())?;
+        expect_distinct(&points)?;
+        self.check_degree(points.len() as u64 - 1)?;
+
+        // the product of (x - x_j) / (x_i - x_j) over the other points
+        let others = [&points[..i], &points[i + 1..]].concat();
+        let denominator = others.iter().fold(FieldElement::<F>::one(), |acc, x_j| {
+            acc * (&points[i] - x_j)
+        });
+        let scale = denominator.inv().expect("expected distinct points");
+        Ok((&scale * &vanishing_over(&others)).into())
+    }
+
+    /// `len(v)` returns the number of elements of a list or a tuple.
+    fn builtin_len(args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [values] = args;
+        let len = match values {
+            Value::List(values) | Value::Tuple(values) => values.len(),
+            value => {
+                return Err(InterpreterError::TypeError(format!(
+                    "Expected a list, found {}",
+                    value
+                )))
+            }
+        };
+        Ok(Self::felt_as_poly(FieldElement::from(len as u64)).into())
+    }
+
+    /// `range(n)` returns the list `[0, 1, ..., n-1]`.
+    fn builtin_range(&self, args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [n] = args;
+        let n = felt_to_u64(&n.expect_felt()?).unwrap_or(u64::MAX);
+        self.check_list_len(n)?;
+        Ok(Value::List(
+            (0..n)
+                .map(|i| Self::felt_as_poly(FieldElement::from(i)).into())
+                .collect(),
+        ))
+    }
+
+    /// `inv(a)` returns the multiplicative inverse of `a`, same as `1 / a`.
+    fn builtin_inv(args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [a] = args;
+        let inverse = a
+            .expect_felt()?
+            .inv()
+            .map_err(|_| InterpreterError::DivisionByZero)?;
+        Ok(Self::felt_as_poly(inverse).into())
+    }
+
+    /// `sqrt(a)` returns a tuple `(r, -r)` of both square roots of `a`, where `r` has the smaller
+    /// canonical representative.
+    fn builtin_sqrt(args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [a] = args;
+        let a = a.expect_felt()?;
+        let (root, other) =
+            felt::sqrt(&a).ok_or_else(|| InterpreterError::NoSquareRoot(Self::felt_print(&a)))?;
+        Ok(Value::Tuple(vec![
+            Self::felt_as_poly(root).into(),
+            Self::felt_as_poly(other).into(),
+        ]))
+    }
+
+    /// `legendre(a)` returns the Legendre symbol of `a`, which is 0 if `a` is zero, 1 if it is a
+    /// square, and -1 otherwise.
+    fn builtin_legendre(args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [a] = args;
+        Ok(Self::felt_as_poly(felt::legendre(&a.expect_felt()?)).into())
+    }
+
+    /// `order(a)` returns the multiplicative order of `a`, i.e. the smallest `n > 0` such that
+    /// `a^n = 1`.
+    fn builtin_order(args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [a] = args;
+        let a = a.expect_felt()?;
+        if a == FieldElement::zero() {
+            return Err(InterpreterError::TypeError(
+                "Expected a non-zero element, found 0".to_string(),
+            ));
+        }
+        let order = felt::order(&a).ok_or_else(no_group_order_error)?;
+        Ok(Self::felt_as_poly(FieldElement::from(order)).into())
+    }
+
+    /// `generator()` returns the smallest generator of the multiplicative group of the field.
+    fn builtin_generator(_: [Value<F>; 0]) -> Result<Value<F>, InterpreterError> {
+        let generator = felt::generator().ok_or_else(no_group_order_error)?;
+        Ok(Self::felt_as_poly(generator).into())
+    }
+
+    /// `root_of_unity(n)` returns a primitive `n`-th root of unity, which is the generator of
+    /// `domain(n)` when `n` is a power of two, and `generator()^((p-1)/n)` otherwise.
+    fn builtin_root_of_unity(args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [n] = args;
+        let n = felt_to_u64(&n.expect_felt()?).unwrap_or(u64::MAX);
+        let no_root_error = || {
+            InterpreterError::UnsupportedField(format!(
+                "No root of unity of order {} in this field",
+                n
+            ))
+        };
+
+        let root = if n.is_power_of_two() {
+            ntt::root_of_unity::<F>(n as usize).ok_or_else(no_root_error)?
+        } else {
+            let group_order =
+                felt_to_u64(&-FieldElement::<F>::one()).ok_or_else(no_group_order_error)?;
+            if n == 0 || !group_order.is_multiple_of(n) {
+                return Err(no_root_error());
+            }
+            let generator = felt::generator::<F>().ok_or_else(no_group_order_error)?;
+            generator.pow(group_order / n)
+        };
+        Ok(Self::felt_as_poly(root).into())
+    }
+
+    /// `ext([a0, a1, ..., ad])` returns the element `a0 + a1*u + ... + ad*u^d` of the extension
+    /// of the field with a degree equal to the number of components.
+    fn builtin_ext(&mut self, args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [components] = args;
+        let components = components
+            .expect_list()?
+            .into_iter()
+            .map(|component| component.expect_felt())
+            .collect::<Result<Vec<_>, _>>()?;
+        let degree = components.len();
+        if degree < 2 {
+            return Err(InterpreterError::TypeError(format!(
+                "Extension degree must be at least 2, found {}",
+                degree
+            )));
+        }
+        if degree > MAX_EXTENSION_DEGREE {
+            return Err(InterpreterError::TypeError(format!(
+                "Extension degree must be at most {}, found {}",
+                MAX_EXTENSION_DEGREE, degree
+            )));
+        }
+        self.check_degree(degree as u64)?;
+
+        let modulus = self.extension_modulus(degree);
+        Ok(Value::Ext(ExtensionElement::new(&components, modulus)))
+    }
+
+    /// `components(e)` returns the components `[a0, a1, ..., ad]` of an extension field element.
+    fn builtin_components(args: [Value<F>; 1]) -> Result<Value<F>, InterpreterError> {
+        let [ext] = args;
+        let Value::Ext(ext) = ext else {
+            return Err(InterpreterError::TypeError(format!(
+                "Expected an extension field element, found {}",
+                ext
+            )));
+        };
+        Ok(Value::List(
+            ext.components()
+                .into_iter()
+                .map(|component| Self::felt_as_poly(component).into())
+                .collect(),
+        ))
+    }
+
+    /// Checks that the order of a domain is within the length limit of lists, and is a power of
+    /// two.
+    fn check_domain_order(&self, order: Option<u64>) -> Result<usize, InterpreterError> {
+        let order = order.unwrap_or(u64::MAX);
+        self.check_list_len(order)?;
+        expect_domain_order(order)
+    }
+}
+
+/// Checks that the order of a domain is a power of two.
+fn expect_domain_order(order: u64) -> Result<usize, InterpreterError> {
+    if !order.is_power_of_two() {
+        return Err(InterpreterError::TypeError(format!(
+            "Domain size must be a power of two, found {}",
+            order
+        )));
+    }
+    Ok(order as usize)
+}
+
+/// Checks that the given x-coordinates are distinct.
+fn expect_distinct<F: IsPrimeField>(xs: &[FieldElement<F>]) -> Result<(), InterpreterError> {
+    match xs
+        .iter()
+        .enumerate()
+        .find_map(|(i, x)| xs[..i].contains(x).then_some(x))
+    {
+        Some(x) => Err(InterpreterError::DuplicateXCoordinate(
+            Polenta::<F>::felt_print(x),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Returns the product of `x - a` over the given points.
+fn vanishing_over<F: IsPrimeField>(points: &[FieldElement<F>]) -> Polynomial<FieldElement<F>> {
+    points.iter().fold(
+        Polynomial::new_monomial(FieldElement::one(), 0),
+        |acc, a| acc * Polynomial::new(&[-a, FieldElement::one()]),
+    )
+}
+
+/// Returns the error for a domain that the field does not have.
+fn no_domain_error(order: usize) -> InterpreterError {
+    InterpreterError::UnsupportedField(format!(
+        "Domain of size {} has no root of unity in this field",
+        order
+    ))
+}
+
+/// Returns the error for fields whose multiplicative group is too large to be factored.
+fn no_group_order_error() -> InterpreterError {
+    InterpreterError::UnsupportedField(
+        "Multiplicative orders are only supported for fields of at most 64 bits".to_string(),
+    )
+}
+
+/// Returns a multiplicity as a field element, which fails unless it is less than the order of the
+/// field, e.g. for `(x - 1)^7` over `F_7`, where it would be reduced to zero.
+fn multiplicity_as_felt<F: IsPrimeField>(
+    multiplicity: usize,
+) -> Result<FieldElement<F>, InterpreterError> {
+    match felt_to_u64(&-FieldElement::<F>::one()) {
+        Some(max) if multiplicity as u64 > max => Err(InterpreterError::UnsupportedField(format!(
+            "Multiplicity {} is not less than the order of the field",
+            multiplicity
+        ))),
+        _ => Ok(FieldElement::from(multiplicity as u64)),
+    }
+}
+
+/// Checks that a polynomial is not zero, e.g. when every element would be a root.
+fn expect_non_zero<F: IsPrimeField>(
+    poly: Polynomial<FieldElement<F>>,
+) -> Result<Polynomial<FieldElement<F>>, InterpreterError> {
+    if Polenta::<F>::poly_is_zero(&poly) {
+        return Err(InterpreterError::TypeError(
+            "Expected a non-zero polynomial, found 0".to_string(),
+        ));
+    }
+    Ok(poly)
+}
+
+/// Checks that a builtin function is called with exactly `N` arguments.
+fn expect_args<F: IsPrimeField, const N: usize>(
+    name: &str,
+    args: Vec<Value<F>>,
+) -> Result<[Value<F>; N], InterpreterError> {
+    let found = args.len();
+    args.try_into()
+        .map_err(|_| InterpreterError::ArityMismatch {
+            name: name.to_string(),
+            expected: N,
+            found,
+        })
+}
@@ -1,5 +1,6 @@
-use miette::{Diagnostic, NamedSource, SourceSpan};
-use pest::error::{Error, ErrorVariant};
+use miette::{Diagnostic, LabeledSpan, NamedSource, SourceCode, SourceSpan};
+use pest::error::{Error, ErrorVariant, InputLocation};
+use std::fmt;
 use thiserror::Error;
 
 use crate::grammar::Rule;
@@ -12,13 +13,19 @@ use crate::grammar::Rule;
 pub enum PolentaError {
     #[error(transparent)]
     #[diagnostic(transparent)]
-    InterpreterError(#[from] InterpreterError),
+    InterpreterError(#[from] RuntimeError),
 
     #[error(transparent)]
     #[diagnostic(transparent)]
     ParserError(#[from] ParserError),
 }
 
+impl From<InterpreterError> for PolentaError {
+    fn from(error: InterpreterError) -> Self {
+        RuntimeError::from(error).into()
+    }
+}
+
 /// An error that can occur during interpretation.
 #[derive(Error, Debug, Diagnostic)]
 pub enum InterpreterError {
@@ -26,16 +33,145 @@ pub enum InterpreterError {
     UnknownIdentifier(String),
     #[error("Division by Zero")]
     DivisionByZero,
-    #[help("Asserted expression must be non-zero.")]
+    #[diagnostic(help(
+        "Use `div` to discard the remainder, or `divmod(P, Q)` for both the quotient and remainder."
+    ))]
+    #[error("Inexact Division: remainder {0}")]
+    InexactDivision(String),
+    #[diagnostic(help("Asserted expression must be non-zero."))]
     #[error("Assertion Failed")]
     AssertionFailed,
+    #[error("Type Error: {0}")]
+    TypeError(String),
+    #[diagnostic(help(
+        "Only constants can be used here, e.g. as an exponent or an evaluation point."
+    ))]
+    #[error("Expected Constant: found a polynomial of degree {found_degree}")]
+    ExpectedConstant { found_degree: usize },
+    #[error("Unsupported Field: {0}")]
+    UnsupportedField(String),
+    #[error("Unknown Function: {0}")]
+    UnknownFunction(String),
+    #[error("Function {name} expects {expected} arguments, found {found}")]
+    ArityMismatch {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    #[diagnostic(help(
+        "Nested function calls are limited in depth, see `Polenta::with_max_call_depth`."
+    ))]
+    #[error("Maximum Call Depth Exceeded: {0}")]
+    CallDepthExceeded(usize),
+    #[diagnostic(help("Interpolation points must have distinct x-coordinates."))]
+    #[error("Duplicate X-Coordinate: {0}")]
+    DuplicateXCoordinate(String),
+    #[diagnostic(help("Degrees of polynomials are limited, see `Polenta::with_max_degree`."))]
+    #[error("Maximum Degree Exceeded: {0}")]
+    DegreeLimitExceeded(usize),
+    #[diagnostic(help(
+        "Factoring is slow for large degrees, see `Polenta::with_max_factor_degree`."
+    ))]
+    #[error("Maximum Factoring Degree Exceeded: {0}")]
+    FactorDegreeExceeded(usize),
+    #[diagnostic(help(
+        "A polynomial is invertible modulo M only if its greatest common divisor with M is 1."
+    ))]
+    #[error("Not Invertible: {0}")]
+    NotInvertible(String),
+    #[diagnostic(help(
+        "Only zero and the elements with a Legendre symbol of 1 have square roots."
+    ))]
+    #[error("No Square Root: {0}")]
+    NoSquareRoot(String),
+    #[error("Index Out of Bounds: {index} for a list of length {len}")]
+    IndexOutOfBounds { index: String, len: usize },
+    #[diagnostic(help("Lengths of lists are limited, see `Polenta::with_max_list_len`."))]
+    #[error("Maximum List Length Exceeded: {0}")]
+    ListLengthExceeded(usize),
+    #[diagnostic(help(
+        "Loop iterations and function calls are limited, see `Polenta::with_max_iterations`."
+    ))]
+    #[error("Maximum Iterations Exceeded: {0}")]
+    IterationLimitExceeded(usize),
+    #[diagnostic(help(
+        "Literals must be less than the order of the field, see `Polenta::with_literal_policy`."
+    ))]
+    #[error("Literal Out of Range: {0}")]
+    LiteralOutOfRange(String),
+}
+
+/// An [`InterpreterError`] along with the source location where it occurred.
+#[derive(Error, Debug)]
+#[error("{error}")]
+pub struct RuntimeError {
+    /// The error that occurred.
+    pub error: InterpreterError,
+    /// Source code of the input that was being interpreted.
+    src: Option<Box<NamedSource<String>>>,
+    /// Location of the innermost expression or statement that failed.
+    span: Option<SourceSpan>,
+}
+
+impl RuntimeError {
+    /// Returns the location of the error within the source, if known.
+    pub fn span(&self) -> Option<SourceSpan> {
+        self.span
+    }
+
+    /// Sets the location of the error, unless it was already located by an inner expression.
+    pub(crate) fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span.get_or_insert(span);
+        self
+    }
+
+    /// Removes the location of the error, e.g. when it refers to another source.
+    pub(crate) fn without_span(mut self) -> Self {
+        self.span = None;
+        self
+    }
+
+    /// Attaches the source code that the location of the error refers to.
+    pub(crate) fn with_source(mut self, src: NamedSource<String>) -> Self {
+        self.src = Some(Box::new(src));
+        self
+    }
+}
+
+impl From<InterpreterError> for RuntimeError {
+    fn from(error: InterpreterError) -> Self {
+        Self {
+            error,
+            src: None,
+            span: None,
+        }
+    }
+}
+
+impl Diagnostic for RuntimeError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.code()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.help()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.src.as_deref().map(|src| src as &dyn SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let span = self.span?;
+        Some(Box::new(std::iter::once(LabeledSpan::underline(span))))
+    }
 }
 
 /// An error that can occur during parsing, most likely a syntax error.
 #[derive(Error, Debug, Diagnostic)]
 #[error("Syntax Error")]
 pub struct ParserError {
-    /// Source line of the error.
+    /// Source code of the input.
     #[source_code]
     src: NamedSource<String>,
     /// The problem location.
@@ -46,13 +182,28 @@ pub struct ParserError {
     help: String,
 }
 
-/// A helper to convert a `pest` error into a `miette` error.
-pub(crate) fn pest_error_to_miette_error(err: Error<Rule>) -> ParserError {
-    let (start, length) = match err.line_col {
-        pest::error::LineColLocation::Pos((_, col)) => (col - 1, 1),
-        pest::error::LineColLocation::Span((_, col_s), (_, col_e)) => {
-            (col_s - 1, col_e - col_s + 1)
-        }
+/// An error that can occur when selecting a field by its name.
+#[derive(Error, Debug, Diagnostic)]
+#[error("Unknown Field: {name}")]
+pub struct UnknownFieldError {
+    /// Name of the field that was not recognized.
+    pub name: String,
+    /// Helpful message listing the supported fields.
+    #[help]
+    pub help: String,
+}
+
+/// A helper to convert a `pest` error into a `miette` error, where `name` and `source` refer to
+/// the whole input that was being parsed.
+pub(crate) fn pest_error_to_miette_error(
+    err: Box<Error<Rule>>,
+    name: &str,
+    source: &str,
+) -> ParserError {
+    let (start, length) = match err.location {
+        // point to a single character, unless the error is at the end of input
+        InputLocation::Pos(pos) => (pos, usize::from(pos < source.len())),
+        InputLocation::Span((start, end)) => (start, end - start),
     };
 
     let help = match &err.variant {
@@ -61,14 +212,23 @@ pub(crate) fn pest_error_to_miette_error(err: Error<Rule>) -> ParserError {
             positives,
             negatives,
         } => {
+            // `keyword` is only looked ahead to mark the end of a keyword, and the keywords
+            // that may follow are already spelled out by the other rules
+            let positives = match positives.as_slice() {
+                [Rule::keyword] => positives.clone(),
+                _ => positives
+                    .iter()
+                    .filter(|rule| **rule != Rule::keyword)
+                    .copied()
+                    .collect(),
+            };
             format!("Expected one of {:?}, got {:?}", positives, negatives)
         }
     };
 
-    let miette_error = ParserError {
-        src: NamedSource::new("input", err.line().to_string()).with_language("Rust"),
+    ParserError {
+        src: NamedSource::new(name, source.to_string()).with_language("Rust"),
         problem: SourceSpan::new(start.into(), length),
         help,
-    };
-    miette_error
+    }
 }
@@ -0,0 +1,177 @@
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsPrimeField},
+    polynomial::Polynomial,
+};
+use std::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use crate::{
+    factor,
+    utils::{felt_to_u64, poly_print_in},
+    Polenta, PolentaUtilExt,
+};
+
+type Poly<F> = Polynomial<FieldElement<F>>;
+
+/// Maximum degree of an extension, as finding its modulus takes much longer for larger degrees.
+pub(crate) const MAX_EXTENSION_DEGREE: usize = 8;
+
+/// An element of the extension field `F[u]/(m(u))` for a monic irreducible modulus `m`, stored as
+/// a polynomial in `u` with a degree less than that of `m`.
+///
+/// Arithmetic is only defined between elements of the same extension, i.e. with the same modulus.
+#[derive(Debug, Clone)]
+pub struct ExtensionElement<F: IsPrimeField> {
+    value: Poly<F>,
+    modulus: Poly<F>,
+}
+
+impl<F: IsPrimeField> ExtensionElement<F> {
+    /// Creates an element from its components, i.e. its coefficients in terms of `u`.
+    pub(crate) fn new(components: &[FieldElement<F>], modulus: &Poly<F>) -> Self {
+        Self::reduce(Polynomial::new(components), modulus)
+    }
+
+    /// Creates an element of the extension from an element of the base field.
+    pub(crate) fn from_base(felt: FieldElement<F>, modulus: &Poly<F>) -> Self {
+        Self::new(&[felt], modulus)
+    }
+
+    /// Returns the degree of the extension.
+    pub fn degree(&self) -> usize {
+        self.modulus.degree()
+    }
+
+    /// Returns the modulus that defines the extension.
+    pub fn modulus(&self) -> &Poly<F> {
+        &self.modulus
+    }
+
+    /// Returns the components of the element, one for each power of `u` below the degree.
+    pub fn components(&self) -> Vec<FieldElement<F>> {
+        let mut components = self.value.coefficients().to_vec();
+        components.resize(self.degree(), FieldElement::zero());
+        components
+    }
+
+    /// Returns the element as an element of the base field, if it is within the base field.
+    pub fn as_base(&self) -> Option<FieldElement<F>> {
+        (self.value.coeff_len() <= 1).then(|| self.value.leading_coefficient())
+    }
+
+    /// Returns true if the element is zero.
+    pub fn is_zero(&self) -> bool {
+        Polenta::poly_is_zero(&self.value)
+    }
+
+    /// Returns the inverse of the element, or `None` if it is zero.
+    pub(crate) fn inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        // the modulus is irreducible, so the greatest common divisor is 1
+        let (_, inverse, _) = Polenta::poly_xgcd(&self.value, &self.modulus);
+        Some(Self::reduce(inverse, &self.modulus))
+    }
+
+    /// Raises the element to the power of the canonical representative of the exponent.
+    pub(crate) fn pow(&self, exponent: &FieldElement<F>) -> Self {
+        let value = factor::pow_mod(&self.value, &exponent.representative(), &self.modulus);
+        Self::reduce(value, &self.modulus)
+    }
+
+    /// Evaluates a polynomial over the base field at this element, with Horner's method.
+    pub(crate) fn evaluate(&self, poly: &Poly<F>) -> Self {
+        poly.coefficients().iter().rev().fold(
+            Self::from_base(FieldElement::zero(), &self.modulus),
+            |acc, coeff| acc * self.clone() + Self::from_base(coeff.clone(), &self.modulus),
+        )
+    }
+
+    /// Creates an element from any polynomial in `u`, by reducing it modulo the modulus.
+    fn reduce(value: Poly<F>, modulus: &Poly<F>) -> Self {
+        let (_, value) = value.long_division_with_remainder(modulus);
+        Self {
+            value,
+            modulus: modulus.clone(),
+        }
+    }
+}
+
+impl<F: IsPrimeField> PartialEq for ExtensionElement<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.modulus == other.modulus
+    }
+}
+
+impl<F: IsPrimeField> Add for ExtensionElement<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.modulus, rhs.modulus);
+        Self::reduce(self.value + rhs.value, &self.modulus)
+    }
+}
+
+impl<F: IsPrimeField> Neg for ExtensionElement<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::reduce(-self.value, &self.modulus)
+    }
+}
+
+impl<F: IsPrimeField> Sub for ExtensionElement<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl<F: IsPrimeField> Mul for ExtensionElement<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.modulus, rhs.modulus);
+        Self::reduce(self.value * rhs.value, &self.modulus)
+    }
+}
+
+impl<F: IsPrimeField> fmt::Display for ExtensionElement<F> {
+    /// Prints the element as a polynomial in `u`, e.g. `3*u + 5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", poly_print_in(&self.value, "u"))
+    }
+}
+
+/// Returns the monic irreducible polynomial of the given degree that defines the extension.
+///
+/// Binomials `u^d - c` come first with the smallest `c`, e.g. `u^2 - 7` over Goldilocks as in
+/// lambdaworks, followed by the other candidates `u^d - r(u)` where the coefficients of `r` are
+/// enumerated as digits below 16, which covers every candidate within the smaller fields.
+pub(crate) fn modulus<F: IsPrimeField>(degree: usize) -> Poly<F> {
+    let base = felt_to_u64(&-FieldElement::<F>::one()).map_or(16, |max| (max + 1).min(16));
+    let mut digits = vec![0; degree];
+    digits[0] = 1;
+    loop {
+        let mut coeffs = digits
+            .iter()
+            .map(|digit| FieldElement::<F>::zero() - FieldElement::from(*digit))
+            .collect::<Vec<_>>();
+        coeffs.push(FieldElement::one());
+        let candidate = Polynomial::new(&coeffs);
+        if let Ok([(_, 1)]) = factor::factor(&candidate).as_deref() {
+            return candidate;
+        }
+
+        for digit in digits.iter_mut() {
+            *digit = (*digit + 1) % base;
+            if *digit != 0 {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsPrimeField},
+    polynomial::Polynomial,
+};
+
+use crate::{utils::felt_to_u64, InterpreterError, Polenta, PolentaUtilExt};
+
+type Poly<F> = Polynomial<FieldElement<F>>;
+
+/// Returns the monic irreducible factors of a non-zero polynomial along with their multiplicities,
+/// sorted by their degrees and then by their coefficients.
+///
+/// The polynomial is first split into square-free parts, each of which is split into products of
+/// factors with the same degree, which are finally split with the Cantor–Zassenhaus algorithm.
+pub(crate) fn factor<F: IsPrimeField>(
+    poly: &Poly<F>,
+) -> Result<Vec<(Poly<F>, usize)>, InterpreterError> {
+    let mut factors = Vec::new();
+    for (square_free, multiplicity) in square_free_decomposition(poly)? {
+        for (part, degree) in distinct_degree(&square_free) {
+            for factor in equal_degree(&part, degree) {
+                factors.push((factor, multiplicity));
+            }
+        }
+    }
+
+    factors.sort_by_key(|(factor, _)| {
+        let coeffs = factor.coefficients().iter().rev();
+        (
+            factor.degree(),
+            coeffs.map(|c| c.representative()).collect::<Vec<_>>(),
+        )
+    });
+    Ok(factors)
+}
+
+/// Returns the roots of a non-zero polynomial along with their multiplicities, sorted by their
+/// canonical representatives.
+pub(crate) fn roots<F: IsPrimeField>(
+    poly: &Poly<F>,
+) -> Result<Vec<(FieldElement<F>, usize)>, InterpreterError> {
+    let x = Polynomial::new_monomial(FieldElement::one(), 1);
+    let mut roots = Vec::new();
+    for (square_free, multiplicity) in square_free_decomposition(poly)? {
+        // the linear factors are shared with x^p - x, which vanishes over the whole field
+        let frobenius = pow_mod(&x, &field_modulus::<F>(), &square_free);
+        let linear = gcd(&square_free, &(frobenius - &x));
+        for factor in equal_degree(&linear, 1) {
+            // subtracting from zero keeps the result reduced, unlike negation within some fields
+            let root = FieldElement::<F>::zero() - &factor.coefficients()[0];
+            roots.push((root, multiplicity));
+        }
+    }
+
+    roots.sort_by_key(|(root, _)| root.representative());
+    Ok(roots)
+}
+
+/// Splits a polynomial into monic square-free parts along with their multiplicities, so that the
+/// polynomial is the product of the parts raised to their multiplicities, up to a constant.
+fn square_free_decomposition<F: IsPrimeField>(
+    poly: &Poly<F>,
+) -> Result<Vec<(Poly<F>, usize)>, InterpreterError> {
+    // the gcd with zero is the monic version of the polynomial
+    let poly = gcd(poly, &Polynomial::zero());
+    let mut parts = Vec::new();
+    let mut c = gcd(&poly, &Polenta::<F>::poly_derivative(&poly));
+    let mut w = div(&poly, &c);
+    let mut i = 1;
+    while w.degree() > 0 {
+        // `w` is the product of the factors with a multiplicity of at least `i`
+        let y = gcd(&w, &c);
+        let part = div(&w, &y);
+        if part.degree() > 0 {
+            parts.push((part, i));
+        }
+        c = div(&c, &y);
+        w = y;
+        i += 1;
+    }
+
+    // whatever remains has a zero derivative, i.e. it is a polynomial in x^p, which is the p-th
+    // power of the polynomial with the same coefficients since the Frobenius map fixes them
+    if c.degree() > 0 {
+        let p = felt_to_u64(&-FieldElement::<F>::one()).ok_or_else(|| {
+            InterpreterError::UnsupportedField(
+                "Polynomials in x^p can only be factored within fields of order below 2^64"
+                    .to_string(),
+            )
+        })? + 1;
+        let coeffs = c
+            .coefficients()
+            .iter()
+            .step_by(p as usize)
+            .cloned()
+            .collect::<Vec<_>>();
+        for (part, multiplicity) in square_free_decomposition(&Polynomial::new(&coeffs))? {
+            parts.push((part, multiplicity * p as usize));
+        }
+    }
+
+    Ok(parts)
+}
+
+/// Splits a monic square-free polynomial into the products of its irreducible factors with the
+/// same degree, along with that degree.
+fn distinct_degree<F: IsPrimeField>(poly: &Poly<F>) -> Vec<(Poly<F>, usize)> {
+    let p = field_modulus::<F>();
+    let x = Polynomial::new_monomial(FieldElement::one(), 1);
+    let mut parts = Vec::new();
+    let mut poly = poly.clone();
+    let mut h = x.clone();
+    let mut degree = 1;
+    while poly.degree() >= 2 * degree {
+        // the factors of degree d are shared with x^(p^d) - x
+        h = pow_mod(&h, &p, &poly);
+        let part = gcd(&poly, &(&h - &x));
+        if part.degree() > 0 {
+            poly = div(&poly, &part);
+            h = rem(&h, &poly);
+            parts.push((part, degree));
+        }
+        degree += 1;
+    }
+
+    // a remaining polynomial without small factors is irreducible
+    if poly.degree() > 0 {
+        let degree = poly.degree();
+        parts.push((poly, degree));
+    }
+    parts
+}
+
+/// Splits a monic square-free polynomial whose irreducible factors all have the given degree.
+///
+/// Candidates are enumerated deterministically, until one of them splits the polynomial.
+fn equal_degree<F: IsPrimeField>(poly: &Poly<F>, degree: usize) -> Vec<Poly<F>> {
+    if poly.degree() == 0 {
+        return vec![];
+    }
+    if poly.degree() == degree {
+        return vec![poly.clone()];
+    }
+
+    let p = field_modulus::<F>();
+    let half_order = F::modulus_minus_one() >> 1;
+    let is_binary = half_order == F::RepresentativeType::from(0u16);
+
+    // enumerate non-constant polynomials, treating their coefficients as digits in base p
+    let mut candidate = vec![FieldElement::<F>::zero(), FieldElement::one()]; // x
+    loop {
+        let a = rem(&Polynomial::new(&candidate), poly);
+
+        // within each factor, the candidate is mapped to the prime field either by the trace for
+        // p = 2, or by the norm raised to (p - 1) / 2, which is a square root of unity otherwise
+        let mut conjugate = a.clone();
+        let mut mapped = a.clone();
+        for _ in 1..degree {
+            conjugate = pow_mod(&conjugate, &p, poly);
+            mapped = if is_binary {
+                mapped + &conjugate
+            } else {
+                rem(&(&mapped * &conjugate), poly)
+            };
+        }
+        if !is_binary {
+            mapped = pow_mod(&mapped, &half_order, poly) - FieldElement::<F>::one();
+        }
+
+        let part = gcd(poly, &mapped);
+        if part.degree() > 0 && part.degree() < poly.degree() {
+            let mut factors = equal_degree(&part, degree);
+            factors.extend(equal_degree(&div(poly, &part), degree));
+            return factors;
+        }
+
+        for digit in candidate.iter_mut() {
+            *digit = &*digit + FieldElement::<F>::one();
+            if *digit != FieldElement::zero() {
+                break;
+            }
+        }
+        if candidate.last() == Some(&FieldElement::zero()) {
+            candidate.push(FieldElement::one());
+        }
+    }
+}
+
+/// Returns the order of the prime field.
+fn field_modulus<F: IsPrimeField>() -> F::RepresentativeType {
+    F::modulus_minus_one() + F::RepresentativeType::from(1u16)
+}
+
+/// Raises a polynomial to the given power modulo another polynomial, with square-and-multiply.
+pub(crate) fn pow_mod<F: IsPrimeField>(
+    poly: &Poly<F>,
+    exponent: &F::RepresentativeType,
+    modulus: &Poly<F>,
+) -> Poly<F> {
+    let (zero, one) = (
+        F::RepresentativeType::from(0),
+        F::RepresentativeType::from(1),
+    );
+    let mut exponent = *exponent;
+    let mut base = rem(poly, modulus);
+    let mut result = rem(&Polynomial::new_monomial(FieldElement::one(), 0), modulus);
+    while exponent != zero {
+        if exponent & one == one {
+            result = rem(&(&result * &base), modulus);
+        }
+        exponent >>= 1;
+        if exponent != zero {
+            base = rem(&(&base * &base), modulus);
+        }
+    }
+
+    result
+}
+
+/// Returns the monic greatest common divisor of the given polynomials.
+fn gcd<F: IsPrimeField>(a: &Poly<F>, b: &Poly<F>) -> Poly<F> {
+    let (gcd, _, _) = Polenta::<F>::poly_xgcd(a, b);
+    gcd
+}
+
+/// Returns the quotient of the division of `a` by `b`.
+fn div<F: IsPrimeField>(a: &Poly<F>, b: &Poly<F>) -> Poly<F> {
+    a.clone().long_division_with_remainder(b).0
+}
+
+/// Returns the remainder of the division of `a` by `b`.
+fn rem<F: IsPrimeField>(a: &Poly<F>, b: &Poly<F>) -> Poly<F> {
+    a.clone().long_division_with_remainder(b).1
+}
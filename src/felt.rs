@@ -0,0 +1,205 @@
+use lambdaworks_math::field::{
+    element::FieldElement,
+    traits::{IsPrimeField, LegendreSymbol},
+};
+
+use crate::utils::felt_to_u64;
+
+/// Returns the Legendre symbol of a field element, which is 0 for zero, 1 for the other squares
+/// and -1 for the rest.
+pub(crate) fn legendre<F: IsPrimeField>(a: &FieldElement<F>) -> FieldElement<F> {
+    // lambdaworks computes a^((p - 1) / 2), which is a^0 = 1 for zero when p = 2
+    if is_binary::<F>() {
+        return a.clone();
+    }
+    match a.legendre_symbol() {
+        LegendreSymbol::Zero => FieldElement::zero(),
+        LegendreSymbol::One => FieldElement::one(),
+        LegendreSymbol::MinusOne => -FieldElement::<F>::one(),
+    }
+}
+
+/// Returns both square roots of a field element sorted by their canonical representatives, or
+/// `None` if it is not a square.
+pub(crate) fn sqrt<F: IsPrimeField>(
+    a: &FieldElement<F>,
+) -> Option<(FieldElement<F>, FieldElement<F>)> {
+    // within the binary field each element is its own square, whereas lambdaworks would search
+    // for a quadratic non-residue forever
+    let root = if is_binary::<F>() {
+        a.clone()
+    } else {
+        a.sqrt()?.0
+    };
+
+    // subtracting from zero keeps the result reduced, unlike negation within some fields
+    let other = FieldElement::<F>::zero() - &root;
+    if root.representative() <= other.representative() {
+        Some((root, other))
+    } else {
+        Some((other, root))
+    }
+}
+
+/// Returns the multiplicative order of a non-zero field element.
+///
+/// Returns `None` if the order of the multiplicative group does not fit in 64 bits, which is
+/// factored to find the order.
+pub(crate) fn order<F: IsPrimeField>(a: &FieldElement<F>) -> Option<u64> {
+    let group_order = felt_to_u64(&-FieldElement::<F>::one())?;
+    let mut order = group_order;
+    for q in prime_factors(group_order) {
+        while order.is_multiple_of(q) && a.pow(order / q) == FieldElement::one() {
+            order /= q;
+        }
+    }
+    Some(order)
+}
+
+/// Returns the smallest generator of the multiplicative group, i.e. the smallest element whose
+/// multiplicative order is `p - 1`.
+///
+/// Returns `None` if the order of the multiplicative group does not fit in 64 bits, see [`order`].
+pub(crate) fn generator<F: IsPrimeField>() -> Option<FieldElement<F>> {
+    let group_order = felt_to_u64(&-FieldElement::<F>::one())?;
+    let factors = prime_factors(group_order);
+
+    // an element generates the group unless it is within a subgroup of prime index
+    (1u64..).map(FieldElement::<F>::from).find(|g| {
+        factors
+            .iter()
+            .all(|q| g.pow(group_order / q) != FieldElement::one())
+    })
+}
+
+/// Returns `(m, s)` such that `p - 1 = 2^s * m` for an odd `m`.
+pub(crate) fn two_adic_split<F: IsPrimeField>() -> (F::RepresentativeType, usize) {
+    let one = F::RepresentativeType::from(1u16);
+    let zero = F::RepresentativeType::from(0u16);
+    let mut m = F::modulus_minus_one();
+    let mut s = 0;
+    while m & one == zero {
+        m >>= 1;
+        s += 1;
+    }
+    (m, s)
+}
+
+/// Returns the smallest quadratic non-residue of the field, which exists unless `p = 2`.
+pub(crate) fn non_residue<F: IsPrimeField>() -> Option<FieldElement<F>> {
+    if is_binary::<F>() {
+        return None;
+    }
+    (2u64..)
+        .map(FieldElement::<F>::from)
+        .find(|g| g.legendre_symbol() == LegendreSymbol::MinusOne)
+}
+
+/// Returns true if the order of the field is 2.
+fn is_binary<F: IsPrimeField>() -> bool {
+    F::modulus_minus_one() == F::RepresentativeType::from(1u16)
+}
+
+/// Returns the distinct prime factors of `n` in increasing order.
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+
+    // small factors are found by trial division, and the large ones with Pollard's rho
+    for q in 2..1 << 10 {
+        if n.is_multiple_of(q) {
+            factors.push(q);
+            while n.is_multiple_of(q) {
+                n /= q;
+            }
+        }
+    }
+
+    let mut composites = vec![n];
+    while let Some(n) = composites.pop() {
+        if n == 1 {
+            continue;
+        }
+        if is_prime(n) {
+            factors.push(n);
+        } else {
+            let d = pollard_rho(n);
+            composites.extend([d, n / d]);
+        }
+    }
+
+    factors.sort_unstable();
+    factors.dedup();
+    factors
+}
+
+/// Returns true if `n` is prime, with the Miller–Rabin test using bases that are known to be
+/// sufficient for 64-bit integers.
+fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(&q) = BASES.iter().find(|&&q| n.is_multiple_of(q)) {
+        return n == q;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    BASES.iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Returns a non-trivial divisor of an odd composite `n`, with Pollard's rho algorithm.
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd(x.abs_diff(y), n);
+        }
+        // the cycle was closed without a divisor, so retry with another polynomial
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!()
+}
+
+/// Returns `a * b mod n`.
+fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+    (a as u128 * b as u128 % n as u128) as u64
+}
+
+/// Returns `a^e mod n`.
+fn pow_mod(mut a: u64, mut e: u64, n: u64) -> u64 {
+    let mut result = 1;
+    while e != 0 {
+        if e & 1 == 1 {
+            result = mul_mod(result, a, n);
+        }
+        a = mul_mod(a, a, n);
+        e >>= 1;
+    }
+    result
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
@@ -0,0 +1,192 @@
+use lambdaworks_math::{
+    elliptic_curve::short_weierstrass::curves::{bls12_381, bn_254},
+    field::{
+        fields::{
+            fft_friendly::{
+                babybear::Babybear31PrimeField, stark_252_prime_field::Stark252PrimeField,
+            },
+            mersenne31::field::Mersenne31Field,
+            u64_goldilocks_field::Goldilocks64Field,
+        },
+        traits::IsPrimeField,
+    },
+};
+use std::{fmt, str::FromStr};
+
+use crate::{errors::UnknownFieldError, Polenta, PolentaError, PolentaUtilExt};
+
+/// A field that can be selected at runtime, see [`AnyPolenta`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PolentaField {
+    /// The 64-bit Goldilocks field, with order `2^64 - 2^32 + 1`.
+    #[default]
+    Goldilocks,
+    /// The 31-bit Mersenne field, with order `2^31 - 1`.
+    Mersenne31,
+    /// The 31-bit BabyBear field, with order `2^31 - 2^27 + 1`.
+    BabyBear,
+    /// The 252-bit STARK field, with order `2^251 + 17 * 2^192 + 1`.
+    Stark252,
+    /// The scalar field of the BN254 curve.
+    Bn254,
+    /// The scalar field of the BLS12-381 curve.
+    Bls12381,
+}
+
+impl PolentaField {
+    /// All fields that can be selected.
+    pub const ALL: [PolentaField; 6] = [
+        PolentaField::Goldilocks,
+        PolentaField::Mersenne31,
+        PolentaField::BabyBear,
+        PolentaField::Stark252,
+        PolentaField::Bn254,
+        PolentaField::Bls12381,
+    ];
+
+    /// Name of the field, as accepted by [`PolentaField::from_str`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            PolentaField::Goldilocks => "goldilocks",
+            PolentaField::Mersenne31 => "mersenne31",
+            PolentaField::BabyBear => "babybear",
+            PolentaField::Stark252 => "stark252",
+            PolentaField::Bn254 => "bn254",
+            PolentaField::Bls12381 => "bls12-381",
+        }
+    }
+}
+
+impl fmt::Display for PolentaField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for PolentaField {
+    type Err = UnknownFieldError;
+
+    /// Parses a field name, ignoring case and dashes so that both `bls12-381` and `BLS12381` work.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalize = |name: &str| name.to_lowercase().replace(['-', '_'], "");
+
+        PolentaField::ALL
+            .into_iter()
+            .find(|field| normalize(field.name()) == normalize(s))
+            .ok_or_else(|| UnknownFieldError {
+                name: s.to_string(),
+                help: format!(
+                    "Expected one of: {}",
+                    PolentaField::ALL.map(|field| field.name()).join(", ")
+                ),
+            })
+    }
+}
+
+/// A Polenta interpreter over a field that is selected at runtime.
+///
+/// Results are returned in their printed form, so that the caller does not have to know about
+/// the underlying field.
+///
+/// ## Example
+///
+/// ```rs
+/// let mut polenta = AnyPolenta::from_field_name("babybear")?;
+/// let results = polenta.interpret("-1;")?;
+/// assert_eq!(results, vec!["2013265920"]);
+/// ```
+pub struct AnyPolenta {
+    field: PolentaField,
+    inner: Box<dyn ErasedPolenta>,
+}
+
+impl AnyPolenta {
+    pub fn new(field: PolentaField) -> Self {
+        let inner: Box<dyn ErasedPolenta> = match field {
+            PolentaField::Goldilocks => Box::new(Polenta::<Goldilocks64Field>::new()),
+            PolentaField::Mersenne31 => Box::new(Polenta::<Mersenne31Field>::new()),
+            PolentaField::BabyBear => Box::new(Polenta::<Babybear31PrimeField>::new()),
+            PolentaField::Stark252 => Box::new(Polenta::<Stark252PrimeField>::new()),
+            PolentaField::Bn254 => Box::new(Polenta::<bn_254::default_types::FrField>::new()),
+            PolentaField::Bls12381 => Box::new(Polenta::<bls12_381::default_types::FrField>::new()),
+        };
+
+        Self { field, inner }
+    }
+
+    /// Creates an interpreter over the field with the given name, see [`PolentaField::from_str`].
+    pub fn from_field_name(name: &str) -> Result<Self, UnknownFieldError> {
+        Ok(Self::new(name.parse()?))
+    }
+
+    /// Returns the field of this interpreter.
+    pub fn field(&self) -> PolentaField {
+        self.field
+    }
+
+    /// Returns the order of the field in decimal form.
+    pub fn order(&self) -> String {
+        self.inner.order()
+    }
+
+    /// Interprets the given input string and returns the printed resulting values,
+    /// see [`Polenta::interpret`].
+    pub fn interpret(&mut self, input: &str) -> Result<Vec<String>, PolentaError> {
+        self.inner.interpret_named("input", input)
+    }
+
+    /// Interprets the given input string like [`Self::interpret`], where `name` is the name of
+    /// the source shown in the errors, see [`Polenta::interpret_named`].
+    pub fn interpret_named(
+        &mut self,
+        name: &str,
+        input: &str,
+    ) -> Result<Vec<String>, PolentaError> {
+        self.inner.interpret_named(name, input)
+    }
+
+    /// Interprets a whole script and returns the printed values of its expression statements,
+    /// see [`Polenta::run`].
+    pub fn run(&mut self, name: &str, input: &str) -> Result<Vec<String>, PolentaError> {
+        self.inner.run(name, input)
+    }
+
+    /// Returns the printed value of a symbol, if it exists.
+    pub fn symbol(&self, identifier: &str) -> Option<String> {
+        self.inner.symbol(identifier)
+    }
+}
+
+impl Default for AnyPolenta {
+    fn default() -> Self {
+        Self::new(PolentaField::default())
+    }
+}
+
+/// Field-agnostic operations of a [`Polenta`] interpreter, used for dynamic dispatch.
+trait ErasedPolenta {
+    fn order(&self) -> String;
+    fn interpret_named(&mut self, name: &str, input: &str) -> Result<Vec<String>, PolentaError>;
+    fn run(&mut self, name: &str, input: &str) -> Result<Vec<String>, PolentaError>;
+    fn symbol(&self, identifier: &str) -> Option<String>;
+}
+
+impl<F: IsPrimeField> ErasedPolenta for Polenta<F> {
+    fn order(&self) -> String {
+        Self::field_order()
+    }
+
+    fn interpret_named(&mut self, name: &str, input: &str) -> Result<Vec<String>, PolentaError> {
+        let values = Polenta::interpret_named(self, name, input)?;
+        Ok(values.iter().map(ToString::to_string).collect())
+    }
+
+    fn run(&mut self, name: &str, input: &str) -> Result<Vec<String>, PolentaError> {
+        let values = Polenta::run(self, name, input)?;
+        Ok(values.iter().map(ToString::to_string).collect())
+    }
+
+    fn symbol(&self, identifier: &str) -> Option<String> {
+        self.symbols.get(identifier).map(ToString::to_string)
+    }
+}
@@ -0,0 +1,349 @@
+use lambdaworks_crypto::merkle_tree::{
+    merkle::MerkleTree, proof::Proof, traits::IsMerkleTreeBackend,
+};
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsPrimeField},
+    polynomial::Polynomial,
+};
+use sha3::{Digest, Keccak256};
+use std::fmt;
+
+use crate::ntt::{evaluate_over_domain, root_of_unity};
+
+/// Default blowup factor of the evaluation domain, when it is not given with `with blowup`.
+pub(crate) const DEFAULT_FRI_BLOWUP: usize = 4;
+
+/// Number of queries made by the verifier.
+const FRI_QUERIES: usize = 8;
+
+/// A Keccak256 digest, used as the nodes of Merkle trees.
+type Hash = [u8; 32];
+
+/// A non-interactive FRI proof that a committed polynomial has a bounded degree.
+///
+/// The polynomial is evaluated over a domain of at least `degree_bound * blowup` roots of unity,
+/// and the evaluations are folded in half with random challenges once for each halving of the
+/// degree bound. Each layer is committed with a Merkle tree, where a leaf holds the pair of
+/// evaluations at `x` and `-x`. The final layer is sent as is, and is constant if and only if
+/// the degree of the polynomial is within the bound.
+#[derive(Debug, Clone)]
+pub struct FriProof<F: IsPrimeField> {
+    degree_bound: usize,
+    blowup: usize,
+    layer_roots: Vec<Hash>,
+    final_layer: Vec<FieldElement<F>>,
+    queries: Vec<Vec<FriDecommitment<F>>>,
+}
+
+/// Opening of a leaf in a layer, i.e. a pair of evaluations and its Merkle path.
+#[derive(Debug, Clone)]
+struct FriDecommitment<F: IsPrimeField> {
+    pair: [FieldElement<F>; 2],
+    path: Vec<Hash>,
+}
+
+impl<F: IsPrimeField> FriProof<F> {
+    /// Creates a proof that the given polynomial has a degree less than the given bound, which
+    /// must be a power of two. The proof is created even if the polynomial exceeds the bound, in
+    /// which case it does not verify.
+    ///
+    /// Returns `None` if the field does not have a root of unity for the evaluation domain.
+    pub(crate) fn prove(
+        poly: &Polynomial<FieldElement<F>>,
+        degree_bound: usize,
+        blowup: usize,
+    ) -> Option<Self> {
+        let domain_size = domain_size(poly, degree_bound, blowup)?;
+        let mut omega = root_of_unity::<F>(domain_size)?;
+
+        let mut transcript = Transcript::new(degree_bound, blowup);
+        let mut evals = evaluate_over_domain(poly, domain_size)?;
+        let mut trees = Vec::new();
+        let mut layers = Vec::new();
+        for _ in 0..degree_bound.trailing_zeros() {
+            let tree = merkle_tree(&evals);
+            transcript.append(&tree.root);
+            let beta = transcript.sample_felt::<F>();
+
+            let next = fold(&evals, &omega, &beta);
+            trees.push(tree);
+            layers.push(evals);
+            evals = next;
+            omega = omega.square();
+        }
+        let final_layer = evals;
+        for felt in &final_layer {
+            transcript.append(felt_bytes(felt).as_slice());
+        }
+
+        let queries = (0..FRI_QUERIES)
+            .map(|_| {
+                let index = transcript.sample_index(domain_size / 2);
+                layers
+                    .iter()
+                    .zip(&trees)
+                    .map(|(evals, tree)| {
+                        let half = evals.len() / 2;
+                        let index = index % half;
+                        FriDecommitment {
+                            pair: [evals[index].clone(), evals[index + half].clone()],
+                            path: tree.get_proof_by_pos(index).unwrap().merkle_path,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Some(Self {
+            degree_bound,
+            blowup,
+            layer_roots: trees.into_iter().map(|tree| tree.root).collect(),
+            final_layer,
+            queries,
+        })
+    }
+
+    /// Verifies the proof, by checking that the final layer is constant, and the Merkle paths and
+    /// the consistency of the folding for each query.
+    pub(crate) fn verify(&self) -> bool {
+        let final_len = self.final_layer.len();
+        if !self.degree_bound.is_power_of_two()
+            || !final_len.is_power_of_two()
+            || final_len < self.blowup
+            || self.layer_roots.len() != self.degree_bound.trailing_zeros() as usize
+            || self.queries.len() != FRI_QUERIES
+        {
+            return false;
+        }
+        if self
+            .final_layer
+            .iter()
+            .any(|felt| *felt != self.final_layer[0])
+        {
+            return false;
+        }
+
+        let domain_size = self.degree_bound * final_len;
+        let Some(omega) = root_of_unity::<F>(domain_size) else {
+            return false;
+        };
+
+        let mut transcript = Transcript::new(self.degree_bound, self.blowup);
+        let betas = self
+            .layer_roots
+            .iter()
+            .map(|root| {
+                transcript.append(root);
+                transcript.sample_felt::<F>()
+            })
+            .collect::<Vec<_>>();
+        for felt in &self.final_layer {
+            transcript.append(felt_bytes(felt).as_slice());
+        }
+
+        self.queries.iter().all(|query| {
+            if query.len() != self.layer_roots.len() {
+                return false;
+            }
+
+            let index = transcript.sample_index(domain_size / 2);
+            let mut omega = omega.clone();
+            let mut half = domain_size / 2;
+            for (i, decommitment) in query.iter().enumerate() {
+                let index = index % half;
+                let leaf = hash_pair(&decommitment.pair);
+                let proof = Proof {
+                    merkle_path: decommitment.path.clone(),
+                };
+                if !proof.verify::<FriBackend>(&self.layer_roots[i], index, &leaf) {
+                    return false;
+                }
+
+                let [a, b] = &decommitment.pair;
+                let folded = fold_pair(a, b, &omega.pow(index as u64), &betas[i]);
+                let expected = match query.get(i + 1) {
+                    Some(next) => &next.pair[usize::from(index >= half / 2)],
+                    None => &self.final_layer[index],
+                };
+                if folded != *expected {
+                    return false;
+                }
+
+                omega = omega.square();
+                half /= 2;
+            }
+            true
+        })
+    }
+
+    /// The degree of the committed polynomial is less than this bound.
+    pub fn degree_bound(&self) -> usize {
+        self.degree_bound
+    }
+
+    /// Blowup factor of the evaluation domain.
+    pub fn blowup(&self) -> usize {
+        self.blowup
+    }
+
+    /// Root of the Merkle tree of the evaluations of the committed polynomial.
+    ///
+    /// Constant polynomials are not folded at all, in which case the root is `None`.
+    pub fn root(&self) -> Option<&[u8; 32]> {
+        self.layer_roots.first()
+    }
+}
+
+/// Proofs are equal if they commit to the same evaluations, as the queries are derived from them.
+impl<F: IsPrimeField> PartialEq for FriProof<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.degree_bound == other.degree_bound
+            && self.blowup == other.blowup
+            && self.layer_roots == other.layer_roots
+            && self.final_layer == other.final_layer
+    }
+}
+
+impl<F: IsPrimeField> fmt::Display for FriProof<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "fri(")?;
+        if let Some(root) = self.root() {
+            write!(f, "0x")?;
+            for byte in root {
+                write!(f, "{:02x}", byte)?;
+            }
+            write!(f, ", ")?;
+        }
+        write!(f, "degree < {}, blowup {})", self.degree_bound, self.blowup)
+    }
+}
+
+/// Size of the evaluation domain for proving the given degree bound, which is large enough for
+/// the evaluations to determine the polynomial even if it exceeds the bound.
+///
+/// Returns `None` if the size overflows.
+pub(crate) fn domain_size<F: IsPrimeField>(
+    poly: &Polynomial<FieldElement<F>>,
+    degree_bound: usize,
+    blowup: usize,
+) -> Option<usize> {
+    degree_bound
+        .max(poly.coeff_len().next_power_of_two())
+        .checked_mul(blowup)
+}
+
+/// Folds the evaluations of `f` over a domain generated by `omega`, so that the result is the
+/// evaluations of `f_even + beta * f_odd` over the domain generated by `omega^2`.
+fn fold<F: IsPrimeField>(
+    evals: &[FieldElement<F>],
+    omega: &FieldElement<F>,
+    beta: &FieldElement<F>,
+) -> Vec<FieldElement<F>> {
+    let (lhs, rhs) = evals.split_at(evals.len() / 2);
+    let mut x = FieldElement::<F>::one();
+    lhs.iter()
+        .zip(rhs)
+        .map(|(a, b)| {
+            let folded = fold_pair(a, b, &x, beta);
+            x = &x * omega;
+            folded
+        })
+        .collect()
+}
+
+/// Given `a = f(x)` and `b = f(-x)`, returns `f_even(x^2) + beta * f_odd(x^2)`.
+fn fold_pair<F: IsPrimeField>(
+    a: &FieldElement<F>,
+    b: &FieldElement<F>,
+    x: &FieldElement<F>,
+    beta: &FieldElement<F>,
+) -> FieldElement<F> {
+    let two_inv = FieldElement::<F>::from(2).inv().unwrap();
+    let even = (a + b) * &two_inv;
+    let odd = (a - b) * two_inv * x.inv().unwrap();
+    even + beta * odd
+}
+
+/// Commits to the evaluations, pairing each evaluation with the one half a domain away.
+fn merkle_tree<F: IsPrimeField>(evals: &[FieldElement<F>]) -> MerkleTree<FriBackend> {
+    let (lhs, rhs) = evals.split_at(evals.len() / 2);
+    let leaves = lhs
+        .iter()
+        .zip(rhs)
+        .map(|(a, b)| hash_pair(&[a.clone(), b.clone()]))
+        .collect::<Vec<_>>();
+    MerkleTree::build(&leaves)
+}
+
+/// Hashes a pair of evaluations into a leaf.
+fn hash_pair<F: IsPrimeField>(pair: &[FieldElement<F>; 2]) -> Hash {
+    let mut hasher = Keccak256::new();
+    for felt in pair {
+        hasher.update(felt_bytes(felt));
+    }
+    hasher.finalize().into()
+}
+
+/// Bytes of a field element, based on its canonical representative.
+fn felt_bytes<F: IsPrimeField>(felt: &FieldElement<F>) -> Vec<u8> {
+    format!("{};", felt.representative()).into_bytes()
+}
+
+/// Merkle tree backend where the leaves are already hashed.
+#[derive(Default)]
+struct FriBackend;
+
+impl IsMerkleTreeBackend for FriBackend {
+    type Node = Hash;
+    type Data = Hash;
+
+    fn hash_data(leaf: &Hash) -> Hash {
+        *leaf
+    }
+
+    fn hash_new_parent(lhs: &Hash, rhs: &Hash) -> Hash {
+        Keccak256::new()
+            .chain_update(lhs)
+            .chain_update(rhs)
+            .finalize()
+            .into()
+    }
+}
+
+/// Fiat-Shamir transcript, where challenges are derived from a running Keccak256 hash.
+struct Transcript {
+    state: Hash,
+}
+
+impl Transcript {
+    /// Creates a transcript bound to the parameters of the proof.
+    fn new(degree_bound: usize, blowup: usize) -> Self {
+        let mut transcript = Self {
+            state: Keccak256::digest(b"polenta-fri").into(),
+        };
+        transcript.append(&(degree_bound as u64).to_le_bytes());
+        transcript.append(&(blowup as u64).to_le_bytes());
+        transcript
+    }
+
+    fn append(&mut self, bytes: &[u8]) {
+        self.state = Keccak256::new()
+            .chain_update(self.state)
+            .chain_update(bytes)
+            .finalize()
+            .into();
+    }
+
+    fn sample_u64(&mut self) -> u64 {
+        self.append(b"challenge");
+        u64::from_le_bytes(self.state[..8].try_into().unwrap())
+    }
+
+    fn sample_felt<F: IsPrimeField>(&mut self) -> FieldElement<F> {
+        FieldElement::from(self.sample_u64())
+    }
+
+    fn sample_index(&mut self, bound: usize) -> usize {
+        (self.sample_u64() % bound as u64) as usize
+    }
+}
@@ -1,3 +1,4 @@
+use miette::SourceSpan;
 use pest::Parser;
 use pest::{iterators::Pair, pratt_parser::PrattParser};
 
@@ -7,8 +8,8 @@ pub struct PolentaParser;
 
 impl PolentaParser {
     /// Parses an input string into a vector of statements.
-    pub fn parse_input(input: &str) -> Result<Vec<Stmt>, pest::error::Error<Rule>> {
-        let pairs = Self::parse(Rule::polenta, input)?;
+    pub fn parse_input(input: &str) -> Result<Vec<Stmt>, Box<pest::error::Error<Rule>>> {
+        let pairs = Self::parse(Rule::polenta, input).map_err(Box::new)?;
 
         let stmts = pairs
             .into_iter()
@@ -27,27 +28,45 @@ impl PolentaParser {
         assert_eq!(pair.as_rule(), Rule::polenta_stmts);
 
         let pair = pair.into_inner().next().unwrap();
-        match pair.as_rule() {
+        let span = span_of(&pair);
+        let kind = match pair.as_rule() {
             Rule::expr_stmt => parse_expr_stmt(pair),
             Rule::let_stmt => parse_let_stmt(pair),
             Rule::let_poly_stmt => parse_let_poly_stmt(pair),
             Rule::assert_stmt => parse_assert_stmt(pair),
+            Rule::fn_stmt => parse_fn_stmt(pair),
+            Rule::commit_stmt => parse_commit_stmt(pair),
+            Rule::prove_stmt => parse_prove_stmt(pair),
+            Rule::fri_commit_stmt => parse_fri_commit_stmt(pair),
+            Rule::assign_stmt => parse_assign_stmt(pair),
+            Rule::block_stmt => parse_block_stmt(pair),
+            Rule::for_stmt => parse_for_stmt(pair),
+            Rule::modulus_stmt => parse_modulus_stmt(pair),
             _ => unreachable!(),
-        }
+        };
+
+        Stmt { kind, span }
     }
 }
 
 /// Binary operators.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub enum BinaryOp {
     Add,
     Sub,
     Mul,
     Div,
+    FloorDiv,
     Mod,
     Pow,
     Eq,
     Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
     Evl,
 }
 
@@ -55,13 +74,26 @@ pub enum BinaryOp {
 #[derive(Debug, Clone)]
 pub enum UnaryOp {
     Minus,
+    Not,
+    Derivative,
+}
+
+/// An expression along with its location in the source.
+#[derive(Debug, Clone)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: SourceSpan,
 }
 
 /// Expressions.
 #[derive(Debug, Clone)]
-pub enum Expr {
+pub enum ExprKind {
     Identifier(String),
-    Integer(u64),
+    Integer(String),
+    Call(String, Vec<Expr>),
+    Bindings(Vec<(String, Expr)>),
+    List(Vec<Expr>),
+    Tuple(Vec<Expr>),
     UnaryOp {
         op: UnaryOp,
         rhs: Box<Expr>,
@@ -71,15 +103,44 @@ pub enum Expr {
         op: BinaryOp,
         rhs: Box<Expr>,
     },
+    Index {
+        lhs: Box<Expr>,
+        index: Box<Expr>,
+    },
+    Comprehension {
+        body: Box<Expr>,
+        var: String,
+        iter: Box<Expr>,
+    },
+    Conditional {
+        condition: Box<Expr>,
+        then_branch: Box<Expr>,
+        else_branch: Box<Expr>,
+    },
+}
+
+/// A statement along with its location in the source.
+#[derive(Debug, Clone)]
+pub struct Stmt {
+    pub kind: StmtKind,
+    pub span: SourceSpan,
 }
 
 /// Statements.
 #[derive(Debug, Clone)]
-pub enum Stmt {
+pub enum StmtKind {
     Expr(Expr),
     Let(String, Expr),
-    LetPoly(String, String, Expr),
+    LetPoly(String, Vec<String>, Expr),
     Assert(Expr),
+    Fn(String, Vec<String>, Expr),
+    Commit(Option<String>, Expr),
+    Prove(Option<String>, Expr),
+    FriCommit(Option<String>, Expr, Option<u64>, Option<u64>),
+    Assign(String, Expr),
+    Block(Vec<Stmt>),
+    For(String, Expr, Vec<Stmt>),
+    Modulus(Expr),
 }
 
 // Pratt parser for expressions with operator precedence.
@@ -91,59 +152,252 @@ lazy_static::lazy_static! {
         // Precedence is defined lowest to highest below.
         // see also: https://en.cppreference.com/w/c/language/operator_precedence
         PrattParser::new()
+            .op(Op::infix(or, Left)) // ||
+            .op(Op::infix(and, Left)) // &&
             .op(Op::infix(eq, Left) | Op::infix(ne, Left)) // ==, !=
+            .op(Op::infix(lt, Left) | Op::infix(le, Left) | Op::infix(gt, Left) | Op::infix(ge, Left)) // <, <=, >, >=
             .op(Op::infix(add, Left) | Op::infix(subtract, Left)) // +, -
-            .op(Op::infix(multiply, Left) | Op::infix(divide, Left) | Op::infix(modulo, Left)) // *, /, %
+            .op(Op::infix(multiply, Left) | Op::infix(divide, Left) | Op::infix(floor_divide, Left) | Op::infix(modulo, Left)) // *, /, div, %
             .op(Op::infix(power, Right)) // ^
             .op(Op::infix(eval, Right)) // @
-            .op(Op::prefix(minus)) // -
+            .op(Op::prefix(minus) | Op::prefix(not)) // -, !
+            .op(Op::postfix(derivative) | Op::postfix(index)) // ', [i]
     };
 }
 
+/// Returns the location of a given `Pair` in the source.
+fn span_of(pair: &Pair<Rule>) -> SourceSpan {
+    let span = pair.as_span();
+    (span.start(), span.end() - span.start()).into()
+}
+
+/// Returns the smallest span that covers both of the given spans, where `start` comes first.
+fn join_spans(start: SourceSpan, end: SourceSpan) -> SourceSpan {
+    (start.offset(), end.offset() + end.len() - start.offset()).into()
+}
+
 /// Parse an expression from a given `Pair`.
 fn parse_expr(pair: Pair<Rule>) -> Expr {
     assert_eq!(pair.as_rule(), Rule::expr);
     let pairs = pair.into_inner();
 
     EXPR_PRATT_PARSER
-        .map_primary(|primary| match primary.as_rule() {
-            Rule::integer => Expr::Integer(primary.as_str().parse::<u64>().unwrap()),
-            Rule::expr => parse_expr(primary),
-            Rule::identifier => Expr::Identifier(primary.as_str().to_string()),
-            rule => unreachable!("Expr::parse expected atom, found {:?}", rule),
+        .map_primary(|primary| {
+            let span = span_of(&primary);
+            let kind = match primary.as_rule() {
+                Rule::integer => ExprKind::Integer(parse_integer(primary)),
+                Rule::group => {
+                    // the location of a parenthesized expression includes the parentheses
+                    let expr = parse_expr(primary.into_inner().next().unwrap());
+                    return Expr { span, ..expr };
+                }
+                Rule::identifier => ExprKind::Identifier(primary.as_str().to_string()),
+                Rule::call => parse_call(primary),
+                Rule::bindings => parse_bindings(primary),
+                Rule::list => parse_list(primary),
+                Rule::tuple => parse_tuple(primary),
+                Rule::comprehension => parse_comprehension(primary),
+                Rule::conditional => parse_conditional(primary),
+                rule => unreachable!("Expr::parse expected atom, found {:?}", rule),
+            };
+            Expr { kind, span }
         })
-        .map_infix(|lhs, op, rhs| Expr::BinaryOp {
-            lhs: Box::new(lhs),
-            op: match op.as_rule() {
-                Rule::add => BinaryOp::Add,
-                Rule::subtract => BinaryOp::Sub,
-                Rule::multiply => BinaryOp::Mul,
-                Rule::divide => BinaryOp::Div,
-                Rule::modulo => BinaryOp::Mod,
-                Rule::power => BinaryOp::Pow,
-                Rule::eval => BinaryOp::Evl,
-                Rule::eq => BinaryOp::Eq,
-                Rule::ne => BinaryOp::Ne,
-                rule => unreachable!("Expr::parse expected infix operation, found {:?}", rule),
+        .map_infix(|lhs, op, rhs| Expr {
+            span: join_spans(lhs.span, rhs.span),
+            kind: ExprKind::BinaryOp {
+                lhs: Box::new(lhs),
+                op: match op.as_rule() {
+                    Rule::add => BinaryOp::Add,
+                    Rule::subtract => BinaryOp::Sub,
+                    Rule::multiply => BinaryOp::Mul,
+                    Rule::divide => BinaryOp::Div,
+                    Rule::floor_divide => BinaryOp::FloorDiv,
+                    Rule::modulo => BinaryOp::Mod,
+                    Rule::power => BinaryOp::Pow,
+                    Rule::eval => BinaryOp::Evl,
+                    Rule::eq => BinaryOp::Eq,
+                    Rule::ne => BinaryOp::Ne,
+                    Rule::lt => BinaryOp::Lt,
+                    Rule::le => BinaryOp::Le,
+                    Rule::gt => BinaryOp::Gt,
+                    Rule::ge => BinaryOp::Ge,
+                    Rule::and => BinaryOp::And,
+                    Rule::or => BinaryOp::Or,
+                    rule => unreachable!("Expr::parse expected infix operation, found {:?}", rule),
+                },
+                rhs: Box::new(rhs),
             },
-            rhs: Box::new(rhs),
         })
-        .map_prefix(|op, rhs| Expr::UnaryOp {
-            op: match op.as_rule() {
-                Rule::minus => UnaryOp::Minus,
-                rule => unreachable!("Expr::parse expected prefix operation, found {:?}", rule),
+        .map_prefix(|op, rhs| Expr {
+            span: join_spans(span_of(&op), rhs.span),
+            kind: ExprKind::UnaryOp {
+                op: match op.as_rule() {
+                    Rule::minus => UnaryOp::Minus,
+                    Rule::not => UnaryOp::Not,
+                    rule => unreachable!("Expr::parse expected prefix operation, found {:?}", rule),
+                },
+                rhs: Box::new(rhs),
             },
-            rhs: Box::new(rhs),
+        })
+        .map_postfix(|lhs, op| {
+            let span = join_spans(lhs.span, span_of(&op));
+            let kind = match op.as_rule() {
+                Rule::derivative => ExprKind::UnaryOp {
+                    op: UnaryOp::Derivative,
+                    rhs: Box::new(lhs),
+                },
+                Rule::index => ExprKind::Index {
+                    lhs: Box::new(lhs),
+                    index: Box::new(parse_expr(op.into_inner().next().unwrap())),
+                },
+                rule => unreachable!("Expr::parse expected postfix operation, found {:?}", rule),
+            };
+            Expr { kind, span }
         })
         .parse(pairs)
 }
 
+/// Parses a function call.
+///
+/// ```rs
+/// <identifier> ( <expr>, ..., <expr> )
+/// ```
+fn parse_call(pair: Pair<Rule>) -> ExprKind {
+    debug_assert_eq!(pair.as_rule(), Rule::call);
+    let mut pairs = pair.into_inner();
+
+    // <identifier> ( <expr>, ..., <expr> )
+    // ^^^^^^^^^^^^
+    let pair = pairs.next().unwrap();
+    debug_assert_eq!(pair.as_rule(), Rule::identifier);
+    let identifier = pair.as_str().to_string();
+
+    // <identifier> ( <expr>, ..., <expr> )
+    //                ^^^^^^^^^^^^^^^^^^^
+    let args = pairs.map(parse_expr).collect();
+
+    ExprKind::Call(identifier, args)
+}
+
+/// Parses a conditional expression.
+///
+/// ```rs
+/// if <expr> then <expr> else <expr>
+/// ```
+fn parse_conditional(pair: Pair<Rule>) -> ExprKind {
+    debug_assert_eq!(pair.as_rule(), Rule::conditional);
+    let mut pairs = pair.into_inner().map(parse_expr);
+
+    // if <expr> then <expr> else <expr>
+    //    ^^^^^^      ^^^^^^      ^^^^^^
+    let (condition, then_branch, else_branch) = (
+        pairs.next().unwrap(),
+        pairs.next().unwrap(),
+        pairs.next().unwrap(),
+    );
+
+    ExprKind::Conditional {
+        condition: Box::new(condition),
+        then_branch: Box::new(then_branch),
+        else_branch: Box::new(else_branch),
+    }
+}
+
+/// Parses a list.
+///
+/// ```rs
+/// [ <expr>, ..., <expr> ]
+/// ```
+fn parse_list(pair: Pair<Rule>) -> ExprKind {
+    debug_assert_eq!(pair.as_rule(), Rule::list);
+
+    // [ <expr>, ..., <expr> ]
+    //   ^^^^^^^^^^^^^^^^^^^
+    ExprKind::List(pair.into_inner().map(parse_expr).collect())
+}
+
+/// Parses a list comprehension.
+///
+/// ```rs
+/// [ <expr> for <identifier> in <expr> ]
+/// ```
+fn parse_comprehension(pair: Pair<Rule>) -> ExprKind {
+    debug_assert_eq!(pair.as_rule(), Rule::comprehension);
+    let mut pairs = pair.into_inner();
+
+    // [ <expr> for <identifier> in <expr> ]
+    //   ^^^^^^
+    let body = parse_expr(pairs.next().unwrap());
+
+    // [ <expr> for <identifier> in <expr> ]
+    //              ^^^^^^^^^^^^
+    let pair = pairs.next().unwrap();
+    debug_assert_eq!(pair.as_rule(), Rule::identifier);
+    let var = pair.as_str().to_string();
+
+    // [ <expr> for <identifier> in <expr> ]
+    //                              ^^^^^^
+    let iter = parse_expr(pairs.next().unwrap());
+
+    ExprKind::Comprehension {
+        body: Box::new(body),
+        var,
+        iter: Box::new(iter),
+    }
+}
+
+/// Parses a tuple, which has at least two elements.
+///
+/// ```rs
+/// ( <expr>, <expr>, ..., <expr> )
+/// ```
+fn parse_tuple(pair: Pair<Rule>) -> ExprKind {
+    debug_assert_eq!(pair.as_rule(), Rule::tuple);
+
+    // ( <expr>, <expr>, ..., <expr> )
+    //   ^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ExprKind::Tuple(pair.into_inner().map(parse_expr).collect())
+}
+
+/// Parses variable bindings.
+///
+/// ```rs
+/// ( <identifier> = <expr>, ..., <identifier> = <expr> )
+/// ```
+fn parse_bindings(pair: Pair<Rule>) -> ExprKind {
+    debug_assert_eq!(pair.as_rule(), Rule::bindings);
+
+    let bindings = pair
+        .into_inner()
+        .map(|pair| {
+            debug_assert_eq!(pair.as_rule(), Rule::binding);
+            let mut pairs = pair.into_inner();
+
+            // <identifier> = <expr>
+            // ^^^^^^^^^^^^
+            let pair = pairs.next().unwrap();
+            debug_assert_eq!(pair.as_rule(), Rule::identifier);
+            let identifier = pair.as_str().to_string();
+
+            // <identifier> = <expr>
+            //                ^^^^^^
+            let pair = pairs.next().unwrap();
+            debug_assert_eq!(pair.as_rule(), Rule::expr);
+            let expr = parse_expr(pair);
+
+            (identifier, expr)
+        })
+        .collect();
+
+    ExprKind::Bindings(bindings)
+}
+
 /// Parses an assert statement.
 ///
 /// ```rs
 /// assert <expr> ;
 /// ```
-fn parse_assert_stmt(pair: Pair<Rule>) -> Stmt {
+fn parse_assert_stmt(pair: Pair<Rule>) -> StmtKind {
     debug_assert_eq!(pair.as_rule(), Rule::assert_stmt);
     let mut pairs = pair.into_inner();
 
@@ -154,7 +408,7 @@ fn parse_assert_stmt(pair: Pair<Rule>) -> Stmt {
     let expr = parse_expr(pair);
 
     debug_assert!(pairs.next().is_none());
-    Stmt::Assert(expr)
+    StmtKind::Assert(expr)
 }
 
 /// Parses an expression statement.
@@ -162,7 +416,7 @@ fn parse_assert_stmt(pair: Pair<Rule>) -> Stmt {
 /// ```rs
 /// <expr> ;
 /// ```
-fn parse_expr_stmt(pair: Pair<Rule>) -> Stmt {
+fn parse_expr_stmt(pair: Pair<Rule>) -> StmtKind {
     debug_assert_eq!(pair.as_rule(), Rule::expr_stmt);
     let mut pairs = pair.into_inner();
 
@@ -173,7 +427,7 @@ fn parse_expr_stmt(pair: Pair<Rule>) -> Stmt {
     let expr = parse_expr(pair);
 
     debug_assert!(pairs.next().is_none());
-    Stmt::Expr(expr)
+    StmtKind::Expr(expr)
 }
 
 /// Parses a let statement.
@@ -181,7 +435,7 @@ fn parse_expr_stmt(pair: Pair<Rule>) -> Stmt {
 /// ```rs
 /// let <identifier> = <expr> ;
 /// ```
-fn parse_let_stmt(pair: Pair<Rule>) -> Stmt {
+fn parse_let_stmt(pair: Pair<Rule>) -> StmtKind {
     debug_assert_eq!(pair.as_rule(), Rule::let_stmt);
     let mut pairs = pair.into_inner();
 
@@ -198,36 +452,267 @@ fn parse_let_stmt(pair: Pair<Rule>) -> Stmt {
     let expr = parse_expr(pair);
 
     debug_assert!(pairs.next().is_none());
-    Stmt::Let(identifier, expr)
+    StmtKind::Let(identifier, expr)
+}
+
+/// Parses an assignment to an existing identifier.
+///
+/// ```rs
+/// <identifier> = <expr> ;
+/// ```
+fn parse_assign_stmt(pair: Pair<Rule>) -> StmtKind {
+    debug_assert_eq!(pair.as_rule(), Rule::assign_stmt);
+    let mut pairs = pair.into_inner();
+
+    // <identifier> = <expr> ;
+    // ^^^^^^^^^^^^
+    let pair = pairs.next().unwrap();
+    debug_assert_eq!(pair.as_rule(), Rule::identifier);
+    let identifier = pair.as_str().to_string();
+
+    // <identifier> = <expr> ;
+    //                ^^^^^^
+    let pair = pairs.next().unwrap();
+    debug_assert_eq!(pair.as_rule(), Rule::expr);
+    let expr = parse_expr(pair);
+
+    debug_assert!(pairs.next().is_none());
+    StmtKind::Assign(identifier, expr)
 }
 
-/// Parses a let statement with a polynomial term.
+/// Parses a let statement with polynomial terms.
 ///
 /// ```rs
-/// let <identifier> ( <identifier> ) = <expr> ;
+/// let <identifier> ( <identifier>, ..., <identifier> ) = <expr> ;
 /// ```
-fn parse_let_poly_stmt(pair: Pair<Rule>) -> Stmt {
+fn parse_let_poly_stmt(pair: Pair<Rule>) -> StmtKind {
     debug_assert_eq!(pair.as_rule(), Rule::let_poly_stmt);
+    let mut pairs = pair.into_inner().collect::<Vec<_>>();
+
+    // let <identifier> ( <identifier>, ..., <identifier> ) = <expr> ;
+    //                                                        ^^^^^^
+    let pair = pairs.pop().unwrap();
+    debug_assert_eq!(pair.as_rule(), Rule::expr);
+    let expr = parse_expr(pair);
+
+    // let <identifier> ( <identifier>, ..., <identifier> ) = <expr> ;
+    //     ^^^^^^^^^^^^   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    let mut identifiers = pairs.into_iter().map(|pair| {
+        debug_assert_eq!(pair.as_rule(), Rule::identifier);
+        pair.as_str().to_string()
+    });
+    let identifier = identifiers.next().unwrap();
+    let terms = identifiers.collect();
+
+    StmtKind::LetPoly(identifier, terms, expr)
+}
+
+/// Parses a function definition.
+///
+/// ```rs
+/// fn <identifier> ( <identifier>, ..., <identifier> ) = <expr> ;
+/// ```
+fn parse_fn_stmt(pair: Pair<Rule>) -> StmtKind {
+    debug_assert_eq!(pair.as_rule(), Rule::fn_stmt);
+    let mut pairs = pair.into_inner().collect::<Vec<_>>();
+
+    // fn <identifier> ( <identifier>, ..., <identifier> ) = <expr> ;
+    //                                                       ^^^^^^
+    let pair = pairs.pop().unwrap();
+    debug_assert_eq!(pair.as_rule(), Rule::expr);
+    let body = parse_expr(pair);
+
+    // fn <identifier> ( <identifier>, ..., <identifier> ) = <expr> ;
+    //    ^^^^^^^^^^^^   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    let mut identifiers = pairs.into_iter().map(|pair| {
+        debug_assert_eq!(pair.as_rule(), Rule::identifier);
+        pair.as_str().to_string()
+    });
+    let identifier = identifiers.next().unwrap();
+    let params = identifiers.collect();
+
+    StmtKind::Fn(identifier, params, body)
+}
+
+/// Parses a commit statement, with an optional identifier to store the commitment.
+///
+/// ```rs
+/// let <identifier> = commit <expr> ;
+/// commit <expr> ;
+/// ```
+fn parse_commit_stmt(pair: Pair<Rule>) -> StmtKind {
+    debug_assert_eq!(pair.as_rule(), Rule::commit_stmt);
+    let (identifier, expr) = parse_optional_let(pair);
+    StmtKind::Commit(identifier, expr)
+}
+
+/// Parses a prove statement, with an optional identifier to store the proof.
+///
+/// ```rs
+/// let <identifier> = prove <expr> ;
+/// prove <expr> ;
+/// ```
+fn parse_prove_stmt(pair: Pair<Rule>) -> StmtKind {
+    debug_assert_eq!(pair.as_rule(), Rule::prove_stmt);
+    let (identifier, expr) = parse_optional_let(pair);
+    StmtKind::Prove(identifier, expr)
+}
+
+/// Parses a FRI commit statement, with an optional identifier to store the proof, an optional
+/// degree bound and an optional blowup factor.
+///
+/// ```rs
+/// let <identifier> = fri_commit <expr> with degree < <integer>, blowup <integer> ;
+/// fri_commit <expr> with blowup <integer> ;
+/// fri_commit <expr> ;
+/// ```
+fn parse_fri_commit_stmt(pair: Pair<Rule>) -> StmtKind {
+    debug_assert_eq!(pair.as_rule(), Rule::fri_commit_stmt);
+    let mut pairs = pair.into_inner().collect::<Vec<_>>();
+
+    // blowup <integer> ;
+    //        ^^^^^^^^^
+    let blowup = match pairs.last().unwrap().as_rule() {
+        Rule::fri_blowup => Some(parse_fri_param(pairs.pop().unwrap())),
+        _ => None,
+    };
+
+    // with degree < <integer>
+    //               ^^^^^^^^^
+    let degree_bound = match pairs.last().unwrap().as_rule() {
+        Rule::fri_degree => Some(parse_fri_param(pairs.pop().unwrap())),
+        _ => None,
+    };
+
+    // fri_commit <expr>
+    //            ^^^^^^
+    let pair = pairs.pop().unwrap();
+    debug_assert_eq!(pair.as_rule(), Rule::expr);
+    let expr = parse_expr(pair);
+
+    // let <identifier> =
+    //     ^^^^^^^^^^^^
+    let identifier = pairs.pop().map(|pair| {
+        debug_assert_eq!(pair.as_rule(), Rule::identifier);
+        pair.as_str().to_string()
+    });
+
+    debug_assert!(pairs.is_empty());
+    StmtKind::FriCommit(identifier, expr, degree_bound, blowup)
+}
+
+/// Parses the integer of a FRI parameter, i.e. its degree bound or blowup factor.
+fn parse_fri_param(pair: Pair<Rule>) -> u64 {
+    debug_assert!(matches!(
+        pair.as_rule(),
+        Rule::fri_degree | Rule::fri_blowup
+    ));
+    parse_small_integer(pair.into_inner().next().unwrap())
+}
+
+/// Parses a block statement.
+///
+/// ```rs
+/// { <stmt> ... <stmt> }
+/// ```
+fn parse_block_stmt(pair: Pair<Rule>) -> StmtKind {
+    debug_assert_eq!(pair.as_rule(), Rule::block_stmt);
+    StmtKind::Block(parse_block(pair.into_inner().next().unwrap()))
+}
+
+/// Parses a for loop over the items of a list.
+///
+/// ```rs
+/// for <identifier> in <expr> { <stmt> ... <stmt> }
+/// ```
+fn parse_for_stmt(pair: Pair<Rule>) -> StmtKind {
+    debug_assert_eq!(pair.as_rule(), Rule::for_stmt);
     let mut pairs = pair.into_inner();
 
-    // let <identifier> ( <identifier> ) = <expr> ;
+    // for <identifier> in <expr> { <stmt> ... <stmt> }
     //     ^^^^^^^^^^^^
     let pair = pairs.next().unwrap();
     debug_assert_eq!(pair.as_rule(), Rule::identifier);
     let identifier = pair.as_str().to_string();
 
-    // let <identifier> ( <identifier> ) = <expr> ;
-    //                    ^^^^^^^^^^^^
+    // for <identifier> in <expr> { <stmt> ... <stmt> }
+    //                     ^^^^^^
     let pair = pairs.next().unwrap();
-    debug_assert_eq!(pair.as_rule(), Rule::identifier);
-    let term = pair.as_str().to_string();
+    debug_assert_eq!(pair.as_rule(), Rule::expr);
+    let iter = parse_expr(pair);
+
+    // for <identifier> in <expr> { <stmt> ... <stmt> }
+    //                            ^^^^^^^^^^^^^^^^^^^^^
+    let body = parse_block(pairs.next().unwrap());
+
+    debug_assert!(pairs.next().is_none());
+    StmtKind::For(identifier, iter, body)
+}
 
-    // let <identifier> ( <identifier> ) = <expr> ;
-    //                                     ^^^^^^
+/// Parses a statement that sets the modulus of the quotient ring.
+///
+/// ```rs
+/// modulus <expr> ;
+/// ```
+fn parse_modulus_stmt(pair: Pair<Rule>) -> StmtKind {
+    debug_assert_eq!(pair.as_rule(), Rule::modulus_stmt);
+    let mut pairs = pair.into_inner();
+
+    // modulus <expr> ;
+    //         ^^^^^^
     let pair = pairs.next().unwrap();
     debug_assert_eq!(pair.as_rule(), Rule::expr);
     let expr = parse_expr(pair);
 
     debug_assert!(pairs.next().is_none());
-    Stmt::LetPoly(identifier, term, expr)
+    StmtKind::Modulus(expr)
+}
+
+/// Parses the statements within a block.
+fn parse_block(pair: Pair<Rule>) -> Vec<Stmt> {
+    debug_assert_eq!(pair.as_rule(), Rule::block);
+    pair.into_inner()
+        .map(PolentaParser::parse_statement)
+        .collect()
+}
+
+/// Parses an integer literal, removing its `_` separators while keeping its `0x` or `0b` prefix.
+fn parse_integer(pair: Pair<Rule>) -> String {
+    debug_assert_eq!(pair.as_rule(), Rule::integer);
+    pair.as_str().replace('_', "")
+}
+
+/// Parses an integer literal that is expected to fit in 64 bits, saturating otherwise.
+fn parse_small_integer(pair: Pair<Rule>) -> u64 {
+    let literal = parse_integer(pair);
+    let value = if let Some(hex) = literal.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16)
+    } else if let Some(bin) = literal.strip_prefix("0b") {
+        u64::from_str_radix(bin, 2)
+    } else {
+        literal.parse()
+    };
+    value.unwrap_or(u64::MAX)
+}
+
+/// Parses the optional identifier and the expression of a statement that may be prefixed with
+/// `let <identifier> =`.
+fn parse_optional_let(pair: Pair<Rule>) -> (Option<String>, Expr) {
+    let mut pairs = pair.into_inner().collect::<Vec<_>>();
+
+    // <expr> ;
+    // ^^^^^^
+    let pair = pairs.pop().unwrap();
+    debug_assert_eq!(pair.as_rule(), Rule::expr);
+    let expr = parse_expr(pair);
+
+    // let <identifier> =
+    //     ^^^^^^^^^^^^
+    let identifier = pairs.pop().map(|pair| {
+        debug_assert_eq!(pair.as_rule(), Rule::identifier);
+        pair.as_str().to_string()
+    });
+
+    debug_assert!(pairs.is_empty());
+    (identifier, expr)
 }
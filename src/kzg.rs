@@ -0,0 +1,130 @@
+use lambdaworks_crypto::{
+    commitments::{
+        kzg::{KateZaveruchaGoldberg, StructuredReferenceString},
+        traits::IsCommitmentScheme,
+    },
+    fiat_shamir::{default_transcript::DefaultTranscript, is_transcript::IsTranscript},
+};
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{
+            curves::bls12_381::{
+                compression::compress_g1_point,
+                curve::BLS12381Curve,
+                default_types::{FrElement, FrField},
+                pairing::BLS12381AtePairing,
+                twist::BLS12381TwistCurve,
+            },
+            point::ShortWeierstrassProjectivePoint,
+        },
+        traits::IsEllipticCurve,
+    },
+    field::{element::FieldElement, traits::IsPrimeField},
+    polynomial::Polynomial,
+};
+
+/// A point on the G1 group of BLS12-381, used for both commitments and opening proofs.
+pub type KzgPoint = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+
+/// Default seed of the toxic waste for the structured reference string,
+/// see [`Polenta::with_srs_seed`](crate::Polenta::with_srs_seed).
+pub const DEFAULT_SRS_SEED: &str = "polenta";
+
+/// KZG commitment scheme over BLS12-381 with a deterministic structured reference string (SRS).
+///
+/// The toxic waste is derived from a seed, so anyone who knows the seed can forge proofs; this is
+/// only meant for experimenting with commitments. The SRS is extended on demand as polynomials
+/// with higher degrees are committed.
+pub(crate) struct Kzg {
+    toxic_waste: FrElement,
+    powers_main_group: Vec<KzgPoint>,
+    scheme: KateZaveruchaGoldberg<FrField, BLS12381AtePairing>,
+}
+
+impl Kzg {
+    pub fn new(seed: &str) -> Self {
+        let toxic_waste = DefaultTranscript::<FrField>::new(seed.as_bytes()).sample_field_element();
+        let g1 = BLS12381Curve::generator();
+        let g2 = BLS12381TwistCurve::generator();
+
+        let powers_main_group = vec![g1];
+        let powers_secondary_group = [
+            g2.clone(),
+            g2.operate_with_self(toxic_waste.representative()),
+        ];
+        let scheme = KateZaveruchaGoldberg::new(StructuredReferenceString::new(
+            &powers_main_group,
+            &powers_secondary_group,
+        ));
+
+        Self {
+            toxic_waste,
+            powers_main_group,
+            scheme,
+        }
+    }
+
+    /// Commits to the given polynomial.
+    pub fn commit(&mut self, poly: &Polynomial<FrElement>) -> KzgPoint {
+        self.extend_srs(poly.coeff_len());
+        self.scheme.commit(poly)
+    }
+
+    /// Creates a proof that the given polynomial evaluates to `y` at `z`.
+    pub fn open(&mut self, z: &FrElement, y: &FrElement, poly: &Polynomial<FrElement>) -> KzgPoint {
+        self.extend_srs(poly.coeff_len());
+        self.scheme.open(z, y, poly)
+    }
+
+    /// Verifies that the committed polynomial evaluates to `y` at `z`.
+    pub fn verify(
+        &self,
+        z: &FrElement,
+        y: &FrElement,
+        commitment: &KzgPoint,
+        proof: &KzgPoint,
+    ) -> bool {
+        self.scheme.verify(z, y, commitment, proof)
+    }
+
+    /// Extends the powers of toxic waste in the SRS so that it has at least `len` of them.
+    fn extend_srs(&mut self, len: usize) {
+        if self.powers_main_group.len() >= len {
+            return;
+        }
+
+        let tau = self.toxic_waste.representative();
+        while self.powers_main_group.len() < len {
+            let last = self.powers_main_group.last().unwrap();
+            self.powers_main_group.push(last.operate_with_self(tau));
+        }
+
+        let g2 = BLS12381TwistCurve::generator();
+        let powers_secondary_group = [g2.clone(), g2.operate_with_self(tau)];
+        self.scheme = KateZaveruchaGoldberg::new(StructuredReferenceString::new(
+            &self.powers_main_group,
+            &powers_secondary_group,
+        ));
+    }
+}
+
+/// Returns true if the given field is the scalar field of BLS12-381, which KZG works over.
+pub(crate) fn is_kzg_field<F: IsPrimeField>() -> bool {
+    F::modulus_minus_one().to_string() == FrField::modulus_minus_one().to_string()
+}
+
+/// Converts an element of a field to the scalar field of BLS12-381, assuming that
+/// [`is_kzg_field`] holds for the given field.
+pub(crate) fn to_kzg_felt<F: IsPrimeField>(felt: &FieldElement<F>) -> FrElement {
+    FrElement::from_hex(&felt.representative().to_string()).expect("expected the same field")
+}
+
+/// Prints a point in its compressed form, as a hexadecimal string.
+pub(crate) fn kzg_point_print(point: &KzgPoint) -> String {
+    let hex = compress_g1_point(point)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+    format!("0x{}", hex)
+}
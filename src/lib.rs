@@ -1,10 +1,27 @@
 #![doc = include_str!("../README.md")]
 
+mod builtins;
 mod errors;
+mod extension;
+mod factor;
+mod felt;
+mod fields;
+mod fri;
 mod grammar;
+mod kzg;
+mod multivariate;
+mod ntt;
 mod program;
 mod utils;
 
-pub use errors::PolentaError;
-pub use program::Polenta;
+pub use errors::{InterpreterError, PolentaError, RuntimeError, UnknownFieldError};
+pub use extension::ExtensionElement;
+pub use fields::{AnyPolenta, PolentaField};
+pub use fri::FriProof;
+pub use kzg::{KzgPoint, DEFAULT_SRS_SEED};
+pub use multivariate::MultiPolynomial;
+pub use program::{
+    LiteralPolicy, Polenta, Value, DEFAULT_MAX_CALL_DEPTH, DEFAULT_MAX_DEGREE,
+    DEFAULT_MAX_FACTOR_DEGREE, DEFAULT_MAX_ITERATIONS, DEFAULT_MAX_LIST_LEN,
+};
 pub use utils::PolentaUtilExt;
@@ -0,0 +1,217 @@
+use lambdaworks_math::field::{element::FieldElement, traits::IsField};
+use std::{
+    collections::BTreeMap,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// A sparse multivariate polynomial with named variables.
+///
+/// Each term is stored as a map from the exponents of the variables (in the order of `vars`) to
+/// its coefficient. Terms with zero coefficients are never stored.
+#[derive(Debug, Clone)]
+pub struct MultiPolynomial<F: IsField> {
+    vars: Vec<String>,
+    terms: BTreeMap<Vec<usize>, FieldElement<F>>,
+}
+
+impl<F: IsField> MultiPolynomial<F> {
+    /// Creates a constant polynomial over the given variables.
+    pub fn new_constant(vars: &[String], constant: FieldElement<F>) -> Self {
+        let mut terms = BTreeMap::new();
+        if constant != FieldElement::zero() {
+            terms.insert(vec![0; vars.len()], constant);
+        }
+
+        Self {
+            vars: vars.to_vec(),
+            terms,
+        }
+    }
+
+    /// Creates a polynomial `P(vars) = vars[index]`.
+    pub fn new_variable(vars: &[String], index: usize) -> Self {
+        let mut exponents = vec![0; vars.len()];
+        exponents[index] = 1;
+
+        Self {
+            vars: vars.to_vec(),
+            terms: BTreeMap::from([(exponents, FieldElement::one())]),
+        }
+    }
+
+    /// Names of the variables of this polynomial.
+    pub fn vars(&self) -> &[String] {
+        &self.vars
+    }
+
+    /// Iterates over the terms of this polynomial, as pairs of exponents and coefficients.
+    pub fn terms(&self) -> impl Iterator<Item = (&Vec<usize>, &FieldElement<F>)> {
+        self.terms.iter()
+    }
+
+    /// Returns the variables that appear in at least one term.
+    pub fn occurring_vars(&self) -> Vec<&String> {
+        self.vars
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.terms.keys().any(|exponents| exponents[*i] != 0))
+            .map(|(_, var)| var)
+            .collect()
+    }
+
+    /// Returns the total degree of this polynomial, i.e. the largest sum of exponents of a term.
+    pub fn degree(&self) -> usize {
+        self.terms
+            .keys()
+            .map(|exponents| exponents.iter().sum())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the constant value if no variable appears in this polynomial.
+    pub fn as_constant(&self) -> Option<FieldElement<F>> {
+        match self.terms.len() {
+            0 => Some(FieldElement::zero()),
+            1 => self
+                .terms
+                .iter()
+                .find(|(exponents, _)| exponents.iter().all(|e| *e == 0))
+                .map(|(_, coeff)| coeff.clone()),
+            _ => None,
+        }
+    }
+
+    /// Multiplies each coefficient with the given constant.
+    pub fn scale(&self, factor: &FieldElement<F>) -> Self {
+        let mut result = Self::new_constant(&self.vars, FieldElement::zero());
+        for (exponents, coeff) in &self.terms {
+            result.add_term(exponents.clone(), coeff * factor);
+        }
+        result
+    }
+
+    /// Evaluates the variable with the given name at the given point, returning a polynomial
+    /// over the same variables where the evaluated one does not appear anymore.
+    ///
+    /// Returns `None` if there is no such variable.
+    pub fn evaluate_var(&self, var: &str, point: &FieldElement<F>) -> Option<Self> {
+        let index = self.vars.iter().position(|v| v == var)?;
+
+        let mut result = Self::new_constant(&self.vars, FieldElement::zero());
+        for (exponents, coeff) in &self.terms {
+            let mut exponents = exponents.clone();
+            let factor = point.pow(exponents[index] as u64);
+            exponents[index] = 0;
+            result.add_term(exponents, coeff * factor);
+        }
+        Some(result)
+    }
+
+    /// Adds a term to this polynomial, removing it altogether if the coefficient becomes zero.
+    fn add_term(&mut self, exponents: Vec<usize>, coeff: FieldElement<F>) {
+        let coeff = match self.terms.remove(&exponents) {
+            Some(existing) => existing + coeff,
+            None => coeff,
+        };
+        if coeff != FieldElement::zero() {
+            self.terms.insert(exponents, coeff);
+        }
+    }
+
+    /// Returns copies of both polynomials over the union of their variables, where the variables
+    /// of `self` come first.
+    fn align(&self, other: &Self) -> (Self, Self) {
+        let mut vars = self.vars.clone();
+        for var in &other.vars {
+            if !vars.contains(var) {
+                vars.push(var.clone());
+            }
+        }
+
+        (self.with_vars(&vars), other.with_vars(&vars))
+    }
+
+    /// Returns a copy of this polynomial over the given variables, which must be a superset of
+    /// the current ones.
+    fn with_vars(&self, vars: &[String]) -> Self {
+        let indices = self
+            .vars
+            .iter()
+            .map(|var| vars.iter().position(|v| v == var).unwrap())
+            .collect::<Vec<_>>();
+
+        let terms = self
+            .terms
+            .iter()
+            .map(|(exponents, coeff)| {
+                let mut new_exponents = vec![0; vars.len()];
+                for (i, exponent) in indices.iter().zip(exponents) {
+                    new_exponents[*i] = *exponent;
+                }
+                (new_exponents, coeff.clone())
+            })
+            .collect();
+
+        Self {
+            vars: vars.to_vec(),
+            terms,
+        }
+    }
+}
+
+impl<F: IsField> PartialEq for MultiPolynomial<F> {
+    fn eq(&self, other: &Self) -> bool {
+        let (lhs, rhs) = self.align(other);
+        lhs.terms == rhs.terms
+    }
+}
+
+impl<F: IsField> Add for MultiPolynomial<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (mut lhs, rhs) = self.align(&rhs);
+        for (exponents, coeff) in rhs.terms {
+            lhs.add_term(exponents, coeff);
+        }
+        lhs
+    }
+}
+
+impl<F: IsField> Neg for MultiPolynomial<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.scale(&-FieldElement::<F>::one())
+    }
+}
+
+impl<F: IsField> Sub for MultiPolynomial<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl<F: IsField> Mul for MultiPolynomial<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (lhs, rhs) = self.align(&rhs);
+
+        let mut result = Self::new_constant(&lhs.vars, FieldElement::zero());
+        for (lhs_exponents, lhs_coeff) in &lhs.terms {
+            for (rhs_exponents, rhs_coeff) in &rhs.terms {
+                let exponents = lhs_exponents.iter().zip(rhs_exponents).map(sum).collect();
+                result.add_term(exponents, lhs_coeff * rhs_coeff);
+            }
+        }
+        result
+    }
+}
+
+/// Sums a pair of exponents, used when multiplying monomials.
+fn sum((lhs, rhs): (&usize, &usize)) -> usize {
+    lhs + rhs
+}
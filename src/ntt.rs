@@ -0,0 +1,147 @@
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsPrimeField},
+    polynomial::Polynomial,
+};
+
+use crate::felt::{non_residue, two_adic_split};
+
+/// Multiplications where both polynomials have at least this degree are done with the NTT.
+const NTT_MUL_THRESHOLD: usize = 64;
+
+/// Returns the multiplicative subgroup of the given order, which must be a power of two, as the
+/// powers of its generator.
+///
+/// Returns `None` if the field has no subgroup of that order.
+pub(crate) fn domain<F: IsPrimeField>(order: usize) -> Option<Vec<FieldElement<F>>> {
+    let omega = root_of_unity::<F>(order)?;
+    let mut element = FieldElement::<F>::one();
+    Some(
+        (0..order)
+            .map(|_| {
+                let current = element.clone();
+                element = &element * &omega;
+                current
+            })
+            .collect(),
+    )
+}
+
+/// Evaluates a polynomial over the subgroup of the given order, which must be a power of two.
+///
+/// Returns `None` if the field has no subgroup of that order.
+pub(crate) fn evaluate_over_domain<F: IsPrimeField>(
+    poly: &Polynomial<FieldElement<F>>,
+    order: usize,
+) -> Option<Vec<FieldElement<F>>> {
+    let omega = root_of_unity::<F>(order)?;
+
+    // x^order is 1 over the domain, so higher coefficients wrap around
+    let mut coeffs = vec![FieldElement::<F>::zero(); order];
+    for (i, coeff) in poly.coefficients().iter().enumerate() {
+        coeffs[i % order] += coeff.clone();
+    }
+    Some(ntt(coeffs, &omega))
+}
+
+/// Interpolates the polynomial with the given evaluations over the subgroup of the same order,
+/// which must be a power of two.
+///
+/// Returns `None` if the field has no subgroup of that order.
+pub(crate) fn interpolate_over_domain<F: IsPrimeField>(
+    evals: &[FieldElement<F>],
+) -> Option<Polynomial<FieldElement<F>>> {
+    let omega = root_of_unity::<F>(evals.len())?;
+
+    // the inverse transform is the transform with the inverse root, scaled by 1/n
+    let omega_inv = omega.inv().expect("expected a non-zero root of unity");
+    let order_inv = FieldElement::<F>::from(evals.len() as u64)
+        .inv()
+        .expect("expected the order to be non-zero in the field");
+    let coeffs = ntt(evals.to_vec(), &omega_inv)
+        .into_iter()
+        .map(|coeff| coeff * &order_inv)
+        .collect::<Vec<_>>();
+    Some(Polynomial::new(&coeffs))
+}
+
+/// Multiplies two polynomials, using the NTT if they are large enough and the field has a large
+/// enough subgroup, and the schoolbook method otherwise.
+pub(crate) fn mul<F: IsPrimeField>(
+    lhs: &Polynomial<FieldElement<F>>,
+    rhs: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>> {
+    if lhs.degree().min(rhs.degree()) >= NTT_MUL_THRESHOLD {
+        let order = (lhs.degree() + rhs.degree() + 1).next_power_of_two();
+        if let (Some(lhs), Some(rhs)) = (
+            evaluate_over_domain(lhs, order),
+            evaluate_over_domain(rhs, order),
+        ) {
+            let evals = lhs.iter().zip(&rhs).map(|(a, b)| a * b).collect::<Vec<_>>();
+            return interpolate_over_domain(&evals).expect("expected the same domain");
+        }
+    }
+
+    lhs * rhs
+}
+
+/// Evaluates the polynomial with the given coefficients at the powers of `omega`, a primitive root
+/// of unity with an order equal to the number of coefficients, with the iterative Cooley-Tukey
+/// algorithm.
+fn ntt<F: IsPrimeField>(
+    mut values: Vec<FieldElement<F>>,
+    omega: &FieldElement<F>,
+) -> Vec<FieldElement<F>> {
+    let n = values.len();
+    if n <= 1 {
+        return values;
+    }
+
+    // bit-reversal permutation, so that the butterflies work in-place
+    let log_n = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let omega_len = omega.pow((n / len) as u64);
+        for start in (0..n).step_by(len) {
+            let mut w = FieldElement::<F>::one();
+            for k in start..start + len / 2 {
+                let u = values[k].clone();
+                let v = &values[k + len / 2] * &w;
+                values[k] = &u + &v;
+                values[k + len / 2] = u - v;
+                w *= &omega_len;
+            }
+        }
+        len *= 2;
+    }
+
+    values
+}
+
+/// Returns a primitive root of unity of the given order, which must be a power of two.
+///
+/// Returns `None` if the multiplicative group of the field has no subgroup of that order.
+pub(crate) fn root_of_unity<F: IsPrimeField>(order: usize) -> Option<FieldElement<F>> {
+    // write p - 1 = 2^s * m with odd m
+    let (m, s) = two_adic_split::<F>();
+    let log_order = order.trailing_zeros() as usize;
+    if !order.is_power_of_two() || log_order > s {
+        return None;
+    }
+    if order == 1 {
+        return Some(FieldElement::one());
+    }
+
+    // g^m has order 2^s for a quadratic non-residue g
+    let mut root = non_residue::<F>()?.pow(m);
+    for _ in log_order..s {
+        root = root.square();
+    }
+    Some(root)
+}
@@ -1,20 +1,254 @@
 use crate::{
-    errors::{pest_error_to_miette_error, InterpreterError, PolentaError},
-    utils::PolentaUtilExt,
+    errors::{pest_error_to_miette_error, InterpreterError, PolentaError, RuntimeError},
+    extension::{self, ExtensionElement},
+    factor,
+    fri::{self, FriProof, DEFAULT_FRI_BLOWUP},
+    kzg::{is_kzg_field, kzg_point_print, to_kzg_felt, Kzg, KzgPoint, DEFAULT_SRS_SEED},
+    multivariate::MultiPolynomial,
+    ntt,
+    utils::{felt_to_u64, literal_to_felt, PolentaUtilExt},
 };
 use lambdaworks_math::{
     field::{element::FieldElement, traits::IsPrimeField},
     polynomial::Polynomial,
 };
-use std::collections::HashMap;
+use miette::NamedSource;
+use std::{collections::HashMap, fmt};
 
-use crate::grammar::{BinaryOp, Expr, PolentaParser, Stmt, UnaryOp};
+use crate::grammar::{BinaryOp, Expr, ExprKind, PolentaParser, Stmt, StmtKind, UnaryOp};
+
+/// Default maximum depth of nested function calls, see [`Polenta::with_max_call_depth`].
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 64;
+
+/// Default maximum degree of polynomials, see [`Polenta::with_max_degree`].
+pub const DEFAULT_MAX_DEGREE: usize = 1 << 14;
+
+/// Default maximum degree of polynomials given to `roots` and `factor`, see
+/// [`Polenta::with_max_factor_degree`].
+pub const DEFAULT_MAX_FACTOR_DEGREE: usize = 64;
+
+/// Default maximum length of lists created with `range`, see [`Polenta::with_max_list_len`].
+pub const DEFAULT_MAX_LIST_LEN: usize = 1 << 16;
+
+/// Default maximum number of loop iterations and function calls within an input, see
+/// [`Polenta::with_max_iterations`].
+pub const DEFAULT_MAX_ITERATIONS: usize = 1 << 20;
+
+/// How integer literals that are not less than the order of the field are treated, see
+/// [`Polenta::with_literal_policy`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LiteralPolicy {
+    /// Reduce the literal modulo the order of the field, e.g. `10` is `3` within a field of order 7.
+    #[default]
+    Reduce,
+    /// Reject the literal with an error.
+    Reject,
+}
+
+/// A user-defined function.
+#[derive(Debug, Clone)]
+struct Function {
+    params: Vec<String>,
+    body: Expr,
+}
+
+/// A value that an expression evaluates to.
+#[derive(Debug, Clone)]
+pub enum Value<F: IsPrimeField> {
+    /// A univariate polynomial, constant values are stored as constant polynomials.
+    Poly(Polynomial<FieldElement<F>>),
+    /// A multivariate polynomial with at least one variable.
+    MultiPoly(MultiPolynomial<F>),
+    /// A KZG commitment to a polynomial.
+    Commitment(KzgPoint),
+    /// A KZG opening proof for the evaluation of a polynomial.
+    Proof(KzgPoint),
+    /// A FRI proof that a committed polynomial has a bounded degree.
+    Fri(FriProof<F>),
+    /// An element of an extension of the field.
+    Ext(ExtensionElement<F>),
+    /// A list of values, e.g. `[1, 2, 3]`.
+    List(Vec<Value<F>>),
+    /// A tuple of at least two values, e.g. `(1, 2)`.
+    Tuple(Vec<Value<F>>),
+}
+
+impl<F: IsPrimeField> PartialEq for Value<F> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Poly(lhs), Value::Poly(rhs)) => lhs == rhs,
+            (Value::MultiPoly(lhs), Value::MultiPoly(rhs)) => lhs == rhs,
+            (Value::Commitment(lhs), Value::Commitment(rhs)) => lhs == rhs,
+            (Value::Proof(lhs), Value::Proof(rhs)) => lhs == rhs,
+            (Value::Fri(lhs), Value::Fri(rhs)) => lhs == rhs,
+            (Value::Ext(lhs), Value::Ext(rhs)) => lhs == rhs,
+            // extension elements within the base field are equal to the constants
+            (Value::Ext(ext), Value::Poly(poly)) | (Value::Poly(poly), Value::Ext(ext)) => {
+                poly.coeff_len() <= 1 && ext.as_base() == Some(poly.leading_coefficient())
+            }
+            (Value::List(lhs), Value::List(rhs)) => lhs == rhs,
+            (Value::Tuple(lhs), Value::Tuple(rhs)) => lhs == rhs,
+            _ => false,
+        }
+    }
+}
+
+impl<F: IsPrimeField> Value<F> {
+    /// Returns true if the value is a zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Value::Poly(poly) => Polenta::poly_is_zero(poly),
+            Value::MultiPoly(poly) => poly.terms().next().is_none(),
+            Value::Ext(ext) => ext.is_zero(),
+            _ => false,
+        }
+    }
+
+    /// Returns the constant value, or an error if this is not a constant polynomial.
+    pub(crate) fn expect_felt(&self) -> Result<FieldElement<F>, InterpreterError> {
+        match self {
+            Value::Poly(poly) => Polenta::poly_as_felt(poly),
+            Value::MultiPoly(poly) => {
+                poly.as_constant()
+                    .ok_or(InterpreterError::ExpectedConstant {
+                        found_degree: poly.degree(),
+                    })
+            }
+            value => Err(InterpreterError::TypeError(format!(
+                "Expected a constant, found {}",
+                value
+            ))),
+        }
+    }
+
+    /// Returns the elements of a list, or a type error if this is not a list.
+    pub(crate) fn expect_list(self) -> Result<Vec<Value<F>>, InterpreterError> {
+        match self {
+            Value::List(values) => Ok(values),
+            value => Err(InterpreterError::TypeError(format!(
+                "Expected a list, found {}",
+                value
+            ))),
+        }
+    }
+
+    /// Returns the elements of a tuple with `N` elements, or a type error if this is not one.
+    pub(crate) fn expect_tuple<const N: usize>(self) -> Result<[Value<F>; N], InterpreterError> {
+        match self {
+            Value::Tuple(values) if values.len() == N => Ok(values.try_into().unwrap()),
+            value => Err(InterpreterError::TypeError(format!(
+                "Expected a tuple with {} elements, found {}",
+                N, value
+            ))),
+        }
+    }
+
+    /// Returns the univariate polynomial, or a type error if this is not one.
+    pub(crate) fn expect_poly(self) -> Result<Polynomial<FieldElement<F>>, InterpreterError> {
+        match self {
+            Value::Poly(poly) => Ok(poly),
+            value => Err(InterpreterError::TypeError(format!(
+                "Expected a univariate polynomial, found {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl<F: IsPrimeField> From<Polynomial<FieldElement<F>>> for Value<F> {
+    fn from(poly: Polynomial<FieldElement<F>>) -> Self {
+        Value::Poly(poly)
+    }
+}
+
+impl<F: IsPrimeField> From<MultiPolynomial<F>> for Value<F> {
+    /// Multivariate polynomials without any variables are treated as constants.
+    fn from(poly: MultiPolynomial<F>) -> Self {
+        match poly.as_constant() {
+            Some(constant) => Value::Poly(Polenta::felt_as_poly(constant)),
+            None => Value::MultiPoly(poly),
+        }
+    }
+}
+
+/// Returns the given index as a position within a list of the given length, or an error if it is
+/// out of bounds.
+pub(crate) fn checked_index<F: IsPrimeField>(
+    index: &FieldElement<F>,
+    len: usize,
+) -> Result<usize, InterpreterError> {
+    felt_to_u64(index)
+        .map(|index| index as usize)
+        .filter(|index| *index < len)
+        .ok_or_else(|| InterpreterError::IndexOutOfBounds {
+            index: Polenta::felt_print(index),
+            len,
+        })
+}
+
+/// Logical operations short-circuit when their expression is processed, so they are never
+/// applied to a pair of values.
+fn short_circuit_error() -> InterpreterError {
+    InterpreterError::TypeError("Logical operations must short-circuit".to_string())
+}
+
+/// Prints the given values separated with commas.
+fn join_values<F: IsPrimeField>(values: &[Value<F>]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl<F: IsPrimeField> fmt::Display for Value<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Poly(poly) => write!(f, "{}", Polenta::poly_print(poly)),
+            Value::MultiPoly(poly) => write!(f, "{}", Polenta::multi_poly_print(poly)),
+            Value::Commitment(point) => write!(f, "commitment({})", kzg_point_print(point)),
+            Value::Proof(point) => write!(f, "proof({})", kzg_point_print(point)),
+            Value::Fri(proof) => write!(f, "{}", proof),
+            Value::Ext(ext) => write!(f, "{}", ext),
+            Value::List(values) => write!(f, "[{}]", join_values(values)),
+            Value::Tuple(values) => write!(f, "({})", join_values(values)),
+        }
+    }
+}
 
 /// Polenta interpreter.
 pub struct Polenta<F: IsPrimeField> {
-    /// Symbol table as a map from identifiers to polynomials.
+    /// Symbol table as a map from identifiers to values.
     /// Constant values are stored as constant polynomials.
-    pub symbols: HashMap<String, Polynomial<FieldElement<F>>>,
+    pub symbols: HashMap<String, Value<F>>,
+    /// User-defined functions as a map from identifiers to functions.
+    functions: HashMap<String, Function>,
+    /// Local scopes of the ongoing function calls, the last one belonging to the innermost call
+    /// and the first one to the top level, each as a stack of scopes of blocks and loops.
+    frames: Vec<Vec<HashMap<String, Value<F>>>>,
+    /// Maximum depth of nested function calls.
+    max_call_depth: usize,
+    /// Maximum degree of the polynomials resulting from multiplication and exponentiation.
+    max_degree: usize,
+    /// Maximum degree of the polynomials given to `roots` and `factor`.
+    max_factor_degree: usize,
+    /// Maximum length of the lists created with `range`.
+    max_list_len: usize,
+    /// Maximum number of loop iterations and function calls within an input.
+    max_iterations: usize,
+    /// Number of loop iterations and function calls so far within the current input.
+    iterations: usize,
+    /// Treatment of integer literals that exceed the order of the field.
+    literal_policy: LiteralPolicy,
+    /// Modulus of the quotient ring `F[x]/(M)` set with a `modulus` statement, within which the
+    /// results of operations over univariate polynomials are reduced.
+    modulus: Option<Polynomial<FieldElement<F>>>,
+    /// Moduli of the extensions of the field by their degrees, found when they are first used.
+    extension_moduli: HashMap<usize, Polynomial<FieldElement<F>>>,
+    /// Seed of the toxic waste for KZG commitments.
+    srs_seed: String,
+    /// KZG commitment scheme, created when it is first used.
+    kzg: Option<Kzg>,
 }
 
 impl<F: IsPrimeField> Default for Polenta<F> {
@@ -27,13 +261,90 @@ impl<F: IsPrimeField> Polenta<F> {
     pub fn new() -> Self {
         Self {
             symbols: HashMap::new(),
+            functions: HashMap::new(),
+            frames: vec![Vec::new()],
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            max_degree: DEFAULT_MAX_DEGREE,
+            max_factor_degree: DEFAULT_MAX_FACTOR_DEGREE,
+            max_list_len: DEFAULT_MAX_LIST_LEN,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            iterations: 0,
+            literal_policy: LiteralPolicy::default(),
+            modulus: None,
+            extension_moduli: HashMap::new(),
+            srs_seed: DEFAULT_SRS_SEED.to_string(),
+            kzg: None,
         }
     }
 
-    /// Interprets the given input string and returns the resulting polynomials.
+    /// Sets the maximum depth of nested function calls, which also bounds the recursion.
+    ///
+    /// Defaults to [`DEFAULT_MAX_CALL_DEPTH`].
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> Self {
+        self.max_call_depth = max_call_depth;
+        self
+    }
+
+    /// Sets the maximum degree of the polynomials resulting from multiplication and
+    /// exponentiation, so that e.g. `x^(0-1)` fails instead of exhausting memory.
+    ///
+    /// Defaults to [`DEFAULT_MAX_DEGREE`]. For multivariate polynomials, this limits the total
+    /// degree.
+    pub fn with_max_degree(mut self, max_degree: usize) -> Self {
+        self.max_degree = max_degree;
+        self
+    }
+
+    /// Sets the maximum degree of the polynomials given to `roots` and `factor`, which take much
+    /// longer than the other operations as the degree grows.
+    ///
+    /// Defaults to [`DEFAULT_MAX_FACTOR_DEGREE`].
+    pub fn with_max_factor_degree(mut self, max_factor_degree: usize) -> Self {
+        self.max_factor_degree = max_factor_degree;
+        self
+    }
+
+    /// Sets the maximum length of the lists created with `range`.
+    ///
+    /// Defaults to [`DEFAULT_MAX_LIST_LEN`].
+    pub fn with_max_list_len(mut self, max_list_len: usize) -> Self {
+        self.max_list_len = max_list_len;
+        self
+    }
+
+    /// Sets the maximum number of loop iterations within an input, counting the iterations of
+    /// `for` loops, the items of list comprehensions and the calls of user-defined functions, so
+    /// that runaway scripts fail, including recursive ones.
+    ///
+    /// Defaults to [`DEFAULT_MAX_ITERATIONS`].
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Sets how integer literals that are not less than the order of the field are treated,
+    /// where rejecting them catches e.g. constants meant for another field.
+    ///
+    /// Defaults to [`LiteralPolicy::Reduce`].
+    pub fn with_literal_policy(mut self, literal_policy: LiteralPolicy) -> Self {
+        self.literal_policy = literal_policy;
+        self
+    }
+
+    /// Sets the seed from which the toxic waste of the KZG structured reference string is derived.
+    ///
+    /// Anyone who knows the seed can create false proofs, so commitments made with Polenta are
+    /// only meant for experimenting. Defaults to [`DEFAULT_SRS_SEED`].
+    pub fn with_srs_seed(mut self, seed: &str) -> Self {
+        self.srs_seed = seed.to_string();
+        self.kzg = None;
+        self
+    }
+
+    /// Interprets the given input string and returns the resulting values.
     ///
     /// The input is expected to be composed of several statements, each interpreted in the given
-    /// order and resulting in a polynomial.
+    /// order and resulting in a value, except for function definitions which have no result.
     ///
     /// May throw out a `PolentaError` if an error occurs during interpretation, either within the
     /// parsing step or the interpretation step.
@@ -50,105 +361,873 @@ impl<F: IsPrimeField> Polenta<F> {
     ///
     /// Polenta::<F>::new().interpret(input)?;
     /// ```
-    pub fn interpret(
+    pub fn interpret(&mut self, input: &str) -> Result<Vec<Value<F>>, PolentaError> {
+        self.interpret_named("input", input)
+    }
+
+    /// Interprets the given input string like [`Self::interpret`], where `name` is the name of
+    /// the source shown in the errors, e.g. a file name.
+    ///
+    /// ## Example
+    ///
+    /// ```rs
+    /// let input = std::fs::read_to_string("myfile.pol")?;
+    /// Polenta::<F>::new().interpret_named("myfile.pol", &input)?;
+    /// ```
+    pub fn interpret_named(
         &mut self,
+        name: &str,
         input: &str,
-    ) -> Result<Vec<Polynomial<FieldElement<F>>>, PolentaError> {
-        let stmts = PolentaParser::parse_input(input.trim()).map_err(pest_error_to_miette_error)?;
+    ) -> Result<Vec<Value<F>>, PolentaError> {
+        self.interpret_statements(name, input, false)
+    }
 
-        stmts
+    /// Interprets a whole script, and returns only the values of its expression statements,
+    /// i.e. the values that a script runner would print.
+    ///
+    /// ## Example
+    ///
+    /// ```rs
+    /// let input = "let P(x) = x + 1; P@2; assert P@0 == 1;";
+    /// let results = Polenta::<F>::new().run("input", input)?; // [3]
+    /// ```
+    pub fn run(&mut self, name: &str, input: &str) -> Result<Vec<Value<F>>, PolentaError> {
+        self.interpret_statements(name, input, true)
+    }
+
+    /// Parses and processes the statements of an input, returning the values of either all
+    /// statements or expression statements only.
+    fn interpret_statements(
+        &mut self,
+        name: &str,
+        input: &str,
+        expressions_only: bool,
+    ) -> Result<Vec<Value<F>>, PolentaError> {
+        let stmts = PolentaParser::parse_input(input)
+            .map_err(|err| pest_error_to_miette_error(err, name, input))?;
+        self.iterations = 0;
+
+        let results = stmts
             .into_iter()
-            .map(|stmt| self.process_statement(stmt).map_err(|e| e.into()))
-            .collect()
+            .map(|stmt| {
+                let is_expr = matches!(stmt.kind, StmtKind::Expr(_));
+                let value = self.process_statement(stmt)?;
+                Ok(value.filter(|_| is_expr || !expressions_only))
+            })
+            .collect::<Result<Vec<_>, RuntimeError>>()
+            .map_err(|err| err.with_source(NamedSource::new(name, input.to_string())))?;
+
+        Ok(results.into_iter().flatten().collect())
     }
 
-    fn process_expr(
+    /// Evaluates an expression, where the identifiers in `terms` are treated as the variables of
+    /// a polynomial that is being defined.
+    ///
+    /// Errors are located at the innermost expression that failed.
+    fn process_expr(&mut self, expr: Expr, terms: &[String]) -> Result<Value<F>, RuntimeError> {
+        let span = expr.span;
+        self.process_expr_kind(expr.kind, terms)
+            .map_err(|err| err.with_span(span))
+    }
+
+    /// Evaluates an expression without its location, see [`Self::process_expr`].
+    fn process_expr_kind(
         &mut self,
-        expr: Expr,
-        term: Option<&String>,
-    ) -> Result<Polynomial<FieldElement<F>>, InterpreterError> {
-        match expr {
-            Expr::Identifier(identifier) => {
+        kind: ExprKind,
+        terms: &[String],
+    ) -> Result<Value<F>, RuntimeError> {
+        match kind {
+            ExprKind::Identifier(identifier) => {
                 // if this identifier is a term, treat it as P(x) = x
-                if term
-                    .and_then(|t| if t == &identifier { Some(t) } else { None })
-                    .is_some()
-                {
-                    Ok(Polynomial::new_monomial(FieldElement::one(), 1))
+                if let Some(index) = terms.iter().position(|term| term == &identifier) {
+                    if terms.len() == 1 {
+                        Ok(Polynomial::new_monomial(FieldElement::one(), 1).into())
+                    } else {
+                        Ok(MultiPolynomial::new_variable(terms, index).into())
+                    }
                 } else {
-                    // otherwise, look up the identifier in the symbol table
-                    let value = self.symbols.get(&identifier).cloned();
+                    // otherwise, look up the identifier in the innermost call's scopes,
+                    // and then in the symbol table
+                    let value = self
+                        .frames
+                        .last()
+                        .into_iter()
+                        .flatten()
+                        .rev()
+                        .find_map(|scope| scope.get(&identifier))
+                        .or_else(|| self.symbols.get(&identifier))
+                        .cloned();
+
+                    value.ok_or_else(|| InterpreterError::UnknownIdentifier(identifier).into())
+                }
+            }
+            ExprKind::Integer(literal) => {
+                let (value, is_reduced) = literal_to_felt::<F>(&literal);
+                if is_reduced && self.literal_policy == LiteralPolicy::Reject {
+                    return Err(InterpreterError::LiteralOutOfRange(literal).into());
+                }
+                Ok(Polynomial::new_monomial(value, 0).into())
+            }
+            ExprKind::Call(identifier, args) => {
+                let Some(function) = self.functions.get(&identifier).cloned() else {
+                    if !Self::is_builtin(&identifier) {
+                        return Err(InterpreterError::UnknownFunction(identifier).into());
+                    }
 
-                    match value {
-                        Some(value) => Ok(value),
-                        None => Err(InterpreterError::UnknownIdentifier(identifier).into()),
+                    let args = args
+                        .into_iter()
+                        .map(|arg| self.process_expr(arg, terms))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    return Ok(self.call_builtin(&identifier, args)?);
+                };
+                if function.params.len() != args.len() {
+                    return Err(InterpreterError::ArityMismatch {
+                        name: identifier,
+                        expected: function.params.len(),
+                        found: args.len(),
                     }
+                    .into());
                 }
+                // the first frame belongs to the top level, which is not a call
+                if self.frames.len() > self.max_call_depth {
+                    return Err(InterpreterError::CallDepthExceeded(self.max_call_depth).into());
+                }
+                // calls count as iterations, since the depth alone does not bound recursion
+                self.count_iteration()?;
+
+                // arguments are evaluated within the caller's scope
+                let scope = function
+                    .params
+                    .into_iter()
+                    .zip(args)
+                    .map(|(param, arg)| Ok((param, self.process_expr(arg, terms)?)))
+                    .collect::<Result<HashMap<_, _>, RuntimeError>>()?;
+
+                // the body is evaluated within its own scope, on top of the symbol table;
+                // its errors are located at the call, since the body may be from another input
+                self.frames.push(vec![scope]);
+                let result = self.process_expr(function.body, &[]);
+                self.frames.pop();
+                result.map_err(RuntimeError::without_span)
             }
-            Expr::Integer(value) => Ok(Polynomial::new_monomial(FieldElement::from(value), 0)),
-            Expr::UnaryOp { op, rhs } => match op {
-                UnaryOp::Minus => Ok(-self.process_expr(*rhs, term)?),
+            ExprKind::List(items) => Ok(Value::List(
+                items
+                    .into_iter()
+                    .map(|item| self.process_expr(item, terms))
+                    .collect::<Result<_, _>>()?,
+            )),
+            ExprKind::Tuple(items) => Ok(Value::Tuple(
+                items
+                    .into_iter()
+                    .map(|item| self.process_expr(item, terms))
+                    .collect::<Result<_, _>>()?,
+            )),
+            ExprKind::Bindings(_) => Err(InterpreterError::TypeError(
+                "Bindings can only be used for evaluation, e.g. P@(x=1)".to_string(),
+            )
+            .into()),
+            ExprKind::UnaryOp { op, rhs } => match op {
+                UnaryOp::Minus => match self.process_expr(*rhs, terms)? {
+                    Value::Poly(poly) => Ok(Value::Poly(-poly)),
+                    Value::MultiPoly(poly) => Ok(Value::MultiPoly(-poly)),
+                    Value::Ext(ext) => Ok(Value::Ext(-ext)),
+                    value => {
+                        Err(InterpreterError::TypeError(format!("Cannot negate {}", value)).into())
+                    }
+                },
+                UnaryOp::Not => {
+                    let value = self.process_expr(*rhs, terms)?;
+                    Ok(Self::poly_from_bool(value.is_zero()).into())
+                }
+                UnaryOp::Derivative => {
+                    let poly = self.process_expr(*rhs, terms)?.expect_poly()?;
+                    Ok(Self::poly_derivative(&poly).into())
+                }
             },
-            Expr::BinaryOp { lhs, op, rhs } => {
-                let lhs = self.process_expr(*lhs, term)?;
-                let rhs = self.process_expr(*rhs, term)?;
+            ExprKind::BinaryOp { lhs, op, rhs } => {
+                let lhs = self.process_expr(*lhs, terms)?;
 
+                // logical operations short-circuit, e.g. the rhs of 0 && P is not evaluated
                 match op {
-                    // arithmetic operations
-                    BinaryOp::Add => Ok(lhs + rhs),
-                    BinaryOp::Sub => Ok(lhs - rhs),
-                    BinaryOp::Mul => Ok(lhs * rhs),
-                    BinaryOp::Div => {
-                        if rhs.coeff_len() == 0 {
-                            Err(InterpreterError::DivisionByZero)
-                        } else {
-                            Ok(lhs / rhs)
-                        }
+                    BinaryOp::And if lhs.is_zero() => return Ok(Self::poly_from_bool(false).into()),
+                    BinaryOp::Or if !lhs.is_zero() => return Ok(Self::poly_from_bool(true).into()),
+                    BinaryOp::And | BinaryOp::Or => {
+                        let rhs = self.process_expr(*rhs, terms)?;
+                        return Ok(Self::poly_from_bool(!rhs.is_zero()).into());
                     }
-                    BinaryOp::Mod => Ok(lhs.long_division_with_remainder(&rhs).1),
-                    BinaryOp::Pow => Ok(Self::poly_pow(&lhs, Self::poly_as_felt(&rhs))),
-                    // comparison operations
-                    BinaryOp::Eq => Ok(Self::poly_from_bool(lhs == rhs)),
-                    BinaryOp::Ne => Ok(Self::poly_from_bool(lhs != rhs)),
-                    // evaluation
-                    BinaryOp::Evl => {
-                        Ok(Self::felt_as_poly(lhs.evaluate(&Self::poly_as_felt(&rhs))))
+                    _ => {}
+                }
+
+                // evaluation with bindings, e.g. P@(x=1, y=2)
+                if let (BinaryOp::Evl, ExprKind::Bindings(bindings)) = (&op, &rhs.kind) {
+                    let bindings = bindings
+                        .iter()
+                        .map(|(var, expr)| {
+                            let point = self.process_expr(expr.clone(), terms)?;
+                            let point = point
+                                .expect_felt()
+                                .map_err(|err| RuntimeError::from(err).with_span(expr.span))?;
+                            Ok((var.clone(), point))
+                        })
+                        .collect::<Result<Vec<_>, RuntimeError>>()?;
+
+                    return Ok(Self::process_bindings(lhs, bindings)?);
+                }
+
+                let rhs = self.process_expr(*rhs, terms)?;
+                Ok(self.process_binary_op(op, lhs, rhs)?)
+            }
+            ExprKind::Index { lhs, index } => {
+                let values = match self.process_expr(*lhs, terms)? {
+                    Value::List(values) | Value::Tuple(values) => values,
+                    value => {
+                        return Err(
+                            InterpreterError::TypeError(format!("Cannot index {}", value)).into(),
+                        )
                     }
+                };
+                let index = self.process_expr(*index, terms)?.expect_felt()?;
+                let index = checked_index::<F>(&index, values.len())?;
+                Ok(values.into_iter().nth(index).unwrap())
+            }
+            ExprKind::Conditional {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                // only the branch that is taken is evaluated
+                if self.process_expr(*condition, terms)?.is_zero() {
+                    self.process_expr(*else_branch, terms)
+                } else {
+                    self.process_expr(*then_branch, terms)
+                }
+            }
+            ExprKind::Comprehension { body, var, iter } => {
+                let items = self.process_expr(*iter, terms)?.expect_list()?;
+                let mut results = Vec::with_capacity(items.len());
+                for item in items {
+                    self.count_iteration()?;
+                    // the variable is only visible within the body, on top of the current scope
+                    let scope = HashMap::from([(var.clone(), item)]);
+                    let result = self.within_scope(scope, |polenta| {
+                        polenta.process_expr((*body).clone(), terms)
+                    });
+                    results.push(result?);
                 }
+                Ok(Value::List(results))
             }
         }
     }
 
-    /// The value of last evaluated "expression statement" is stored at `!!` symbol for internal testing.
-    fn process_statement(
-        &mut self,
-        stmt: Stmt,
+    /// Applies a binary operation, which is applied element-wise over lists of equal length.
+    fn process_binary_op(
+        &self,
+        op: BinaryOp,
+        lhs: Value<F>,
+        rhs: Value<F>,
+    ) -> Result<Value<F>, InterpreterError> {
+        match (op, lhs, rhs) {
+            // comparison operations work for all values
+            (BinaryOp::Eq, lhs, rhs) => Ok(Self::poly_from_bool(lhs == rhs).into()),
+            (BinaryOp::Ne, lhs, rhs) => Ok(Self::poly_from_bool(lhs != rhs).into()),
+            (op, Value::List(lhs), Value::List(rhs)) => {
+                if lhs.len() != rhs.len() {
+                    return Err(InterpreterError::TypeError(format!(
+                        "Expected lists of equal length, found lengths {} and {}",
+                        lhs.len(),
+                        rhs.len()
+                    )));
+                }
+                Ok(Value::List(
+                    lhs.into_iter()
+                        .zip(rhs)
+                        .map(|(lhs, rhs)| self.process_binary_op(op, lhs, rhs))
+                        .collect::<Result<_, _>>()?,
+                ))
+            }
+            (op, lhs @ Value::Ext(_), rhs) | (op, lhs, rhs @ Value::Ext(_)) => {
+                self.process_extension_op(op, lhs, rhs)
+            }
+            (op, Value::Poly(lhs), Value::Poly(rhs)) => match &self.modulus {
+                Some(modulus) => Ok(self.process_quotient_op(op, lhs, rhs, modulus)?.into()),
+                None => Ok(self.process_poly_op(op, lhs, rhs)?.into()),
+            },
+            (op, lhs, rhs) => self.process_multi_poly_op(op, lhs, rhs),
+        }
+    }
+
+    /// Applies a binary operation over univariate polynomials within the quotient ring modulo the
+    /// given polynomial, where division multiplies by the inverse of the divisor.
+    fn process_quotient_op(
+        &self,
+        op: BinaryOp,
+        lhs: Polynomial<FieldElement<F>>,
+        rhs: Polynomial<FieldElement<F>>,
+        modulus: &Polynomial<FieldElement<F>>,
+    ) -> Result<Polynomial<FieldElement<F>>, InterpreterError> {
+        let reduce =
+            |poly: Polynomial<FieldElement<F>>| poly.long_division_with_remainder(modulus).1;
+        let result = match op {
+            // division is exact within the quotient ring, so `div` is the same as `/`
+            BinaryOp::Div | BinaryOp::FloorDiv => {
+                if rhs.coeff_len() == 0 {
+                    return Err(InterpreterError::DivisionByZero);
+                }
+                let inverse = Self::invmod(&rhs, modulus)?;
+                ntt::mul(&reduce(lhs), &inverse)
+            }
+            // exponents are not limited by the degree, since the powers are reduced as they go
+            BinaryOp::Pow => {
+                let exponent = Self::poly_as_felt(&rhs)?;
+                factor::pow_mod(&lhs, &exponent.representative(), modulus)
+            }
+            BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul => {
+                self.process_poly_op(op, reduce(lhs), reduce(rhs))?
+            }
+            op => self.process_poly_op(op, lhs, rhs)?,
+        };
+
+        Ok(reduce(result))
+    }
+
+    /// Applies a binary operation over univariate polynomials.
+    fn process_poly_op(
+        &self,
+        op: BinaryOp,
+        lhs: Polynomial<FieldElement<F>>,
+        rhs: Polynomial<FieldElement<F>>,
     ) -> Result<Polynomial<FieldElement<F>>, InterpreterError> {
-        match stmt {
-            Stmt::Let(identifier, expr) => {
-                let poly = self.process_expr(expr, None)?;
-                self.symbols.insert(identifier, poly.clone());
-                Ok(poly)
-            }
-            Stmt::LetPoly(identifier, term, expr) => {
-                let poly = self.process_expr(expr, Some(&term))?;
-                self.symbols.insert(identifier, poly.clone());
-                Ok(poly)
-            }
-            Stmt::Expr(expr) => {
-                let poly = self.process_expr(expr, None)?;
-                self.symbols.insert("!!".to_string(), poly.clone());
-                Ok(poly)
-            }
-            Stmt::Assert(expr) => {
-                let result = self.process_expr(expr, None)?;
+        match op {
+            // arithmetic operations
+            BinaryOp::Add => Ok(lhs + rhs),
+            BinaryOp::Sub => Ok(lhs - rhs),
+            BinaryOp::Mul => {
+                self.check_degree(lhs.degree() as u64 + rhs.degree() as u64)?;
+                Ok(ntt::mul(&lhs, &rhs))
+            }
+            BinaryOp::Div | BinaryOp::FloorDiv => {
+                if rhs.coeff_len() == 0 {
+                    return Err(InterpreterError::DivisionByZero);
+                }
+                // `/` is exact, whereas `div` discards the remainder
+                let (quotient, remainder) = lhs.long_division_with_remainder(&rhs);
+                if matches!(op, BinaryOp::Div) && !Self::poly_is_zero(&remainder) {
+                    return Err(InterpreterError::InexactDivision(Self::poly_print(
+                        &remainder,
+                    )));
+                }
+                Ok(quotient)
+            }
+            BinaryOp::Mod => Ok(lhs.long_division_with_remainder(&rhs).1),
+            BinaryOp::Pow => {
+                let exponent = Self::poly_as_felt(&rhs)?;
+                self.check_pow_degree(lhs.degree(), &exponent)?;
+                Ok(Self::poly_pow(&lhs, exponent))
+            }
+            // comparison operations
+            BinaryOp::Eq => Ok(Self::poly_from_bool(lhs == rhs)),
+            BinaryOp::Ne => Ok(Self::poly_from_bool(lhs != rhs)),
+            BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => Ok(Self::poly_from_bool(
+                Self::compare(op, &Self::poly_as_felt(&lhs)?, &Self::poly_as_felt(&rhs)?),
+            )),
+            BinaryOp::And | BinaryOp::Or => Err(short_circuit_error()),
+            // evaluation
+            BinaryOp::Evl if rhs.coeff_len() <= 1 => {
+                Ok(Self::felt_as_poly(lhs.evaluate(&Self::poly_as_felt(&rhs)?)))
+            }
+            // composition, when the point is a polynomial
+            BinaryOp::Evl => {
+                self.check_degree(lhs.degree() as u64 * rhs.degree() as u64)?;
+                Ok(Self::poly_compose(&lhs, &rhs))
+            }
+        }
+    }
+
+    /// Applies a binary operation where at least one of the operands is a multivariate polynomial.
+    ///
+    /// Constants are treated as multivariate polynomials, but mixing univariate and multivariate
+    /// polynomials is not allowed since the variable of a univariate polynomial is unnamed.
+    fn process_multi_poly_op(
+        &self,
+        op: BinaryOp,
+        lhs: Value<F>,
+        rhs: Value<F>,
+    ) -> Result<Value<F>, InterpreterError> {
+        let as_multi_poly = |value: Value<F>| match value {
+            Value::MultiPoly(poly) => Ok(poly),
+            Value::Poly(poly) if poly.coeff_len() <= 1 => Ok(MultiPolynomial::new_constant(
+                &[],
+                Self::poly_as_felt(&poly)?,
+            )),
+            Value::Poly(poly) => Err(InterpreterError::TypeError(format!(
+                "Cannot mix univariate polynomial {} with multivariate polynomials",
+                Self::poly_print(&poly)
+            ))),
+            value => Err(InterpreterError::TypeError(format!(
+                "Expected a polynomial, found {}",
+                value
+            ))),
+        };
+        let as_felt = |value: &Value<F>| value.expect_felt();
+
+        let result = match op {
+            BinaryOp::Pow => {
+                let (lhs, exponent) = (as_multi_poly(lhs)?, as_felt(&rhs)?);
+                self.check_pow_degree(lhs.degree(), &exponent)?;
+                Self::multi_poly_pow(&lhs, exponent)
+            }
+            BinaryOp::Evl => {
+                let lhs = as_multi_poly(lhs)?;
+                let point = as_felt(&rhs)?;
+                match lhs.occurring_vars().as_slice() {
+                    [var] => lhs.evaluate_var(var, &point).unwrap(),
+                    _ => {
+                        return Err(InterpreterError::TypeError(format!(
+                            "Cannot evaluate {} at a single point, use bindings e.g. P@({}=1)",
+                            Self::multi_poly_print(&lhs),
+                            lhs.vars()[0]
+                        )))
+                    }
+                }
+            }
+            BinaryOp::Div | BinaryOp::FloorDiv => {
+                let divisor = as_felt(&rhs)?;
+                if divisor == FieldElement::zero() {
+                    return Err(InterpreterError::DivisionByZero);
+                }
+                as_multi_poly(lhs)?.scale(&divisor.inv().unwrap())
+            }
+            BinaryOp::Mod => {
+                return Err(InterpreterError::TypeError(
+                    "Modulo is not defined for multivariate polynomials".to_string(),
+                ))
+            }
+            BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
+                let (lhs, rhs) = (as_felt(&lhs)?, as_felt(&rhs)?);
+                return Ok(Self::poly_from_bool(Self::compare(op, &lhs, &rhs)).into());
+            }
+            BinaryOp::And | BinaryOp::Or => return Err(short_circuit_error()),
+            _ => {
+                let (lhs, rhs) = (as_multi_poly(lhs)?, as_multi_poly(rhs)?);
+                match op {
+                    BinaryOp::Add => lhs + rhs,
+                    BinaryOp::Sub => lhs - rhs,
+                    BinaryOp::Mul => {
+                        self.check_degree(lhs.degree() as u64 + rhs.degree() as u64)?;
+                        lhs * rhs
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        };
+
+        Ok(result.into())
+    }
+
+    /// Applies a binary operation where at least one of the operands is an extension field element.
+    ///
+    /// Constants are treated as elements of the extension, and univariate polynomials can be
+    /// evaluated at extension field elements.
+    fn process_extension_op(
+        &self,
+        op: BinaryOp,
+        lhs: Value<F>,
+        rhs: Value<F>,
+    ) -> Result<Value<F>, InterpreterError> {
+        let modulus = match (&lhs, &rhs) {
+            (Value::Ext(ext), _) | (_, Value::Ext(ext)) => ext.modulus().clone(),
+            _ => unreachable!("expected an extension field element"),
+        };
+        let as_ext = |value: Value<F>| match value {
+            Value::Ext(ext) if ext.modulus() == &modulus => Ok(ext),
+            Value::Ext(ext) => Err(InterpreterError::TypeError(format!(
+                "Cannot mix elements of extensions of degrees {} and {}",
+                modulus.degree(),
+                ext.degree()
+            ))),
+            value => Ok(ExtensionElement::from_base(value.expect_felt()?, &modulus)),
+        };
+
+        let result = match op {
+            BinaryOp::Add => as_ext(lhs)? + as_ext(rhs)?,
+            BinaryOp::Sub => as_ext(lhs)? - as_ext(rhs)?,
+            BinaryOp::Mul => as_ext(lhs)? * as_ext(rhs)?,
+            BinaryOp::Div | BinaryOp::FloorDiv => {
+                let inverse = as_ext(rhs)?.inv().ok_or(InterpreterError::DivisionByZero)?;
+                as_ext(lhs)? * inverse
+            }
+            BinaryOp::Pow => as_ext(lhs)?.pow(&rhs.expect_felt()?),
+            BinaryOp::Evl => match lhs {
+                Value::Poly(poly) => as_ext(rhs)?.evaluate(&poly),
+                // evaluating a constant has no effect, as with polynomials
+                lhs => as_ext(lhs)?,
+            },
+            BinaryOp::And | BinaryOp::Or => return Err(short_circuit_error()),
+            BinaryOp::Mod => {
+                return Err(InterpreterError::TypeError(
+                    "Modulo is not defined for extension field elements".to_string(),
+                ))
+            }
+            BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
+                return Err(InterpreterError::TypeError(
+                    "Extension field elements cannot be compared".to_string(),
+                ))
+            }
+            BinaryOp::Eq | BinaryOp::Ne => unreachable!("expected a non-comparison operation"),
+        };
+
+        Ok(Value::Ext(result))
+    }
+
+    /// Compares two constants by their canonical representatives.
+    fn compare(op: BinaryOp, lhs: &FieldElement<F>, rhs: &FieldElement<F>) -> bool {
+        let (lhs, rhs) = (lhs.representative(), rhs.representative());
+        match op {
+            BinaryOp::Lt => lhs < rhs,
+            BinaryOp::Le => lhs <= rhs,
+            BinaryOp::Gt => lhs > rhs,
+            BinaryOp::Ge => lhs >= rhs,
+            _ => unreachable!("expected a comparison, found {:?}", op),
+        }
+    }
+
+    /// Counts a loop iteration or a function call, and returns an error if the iterations exceed
+    /// their maximum.
+    fn count_iteration(&mut self) -> Result<(), InterpreterError> {
+        self.iterations += 1;
+        if self.iterations > self.max_iterations {
+            return Err(InterpreterError::IterationLimitExceeded(
+                self.max_iterations,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Runs the given closure with a new scope on top of the scopes of the innermost call, which
+    /// is removed afterwards even if the closure fails.
+    fn within_scope<T>(
+        &mut self,
+        scope: HashMap<String, Value<F>>,
+        f: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        self.frames.last_mut().unwrap().push(scope);
+        let result = f(self);
+        self.frames.last_mut().unwrap().pop();
+        result
+    }
+
+    /// Defines an identifier within the innermost scope, or in the symbol table at the top level.
+    fn define(&mut self, identifier: String, value: Value<F>) {
+        match self.frames.last_mut().and_then(|scopes| scopes.last_mut()) {
+            Some(scope) => scope.insert(identifier, value),
+            None => self.symbols.insert(identifier, value),
+        };
+    }
+
+    /// Assigns a value to an existing identifier, within the innermost scope that defines it.
+    fn assign(&mut self, identifier: String, value: Value<F>) -> Result<(), InterpreterError> {
+        let scopes = self.frames.last_mut().unwrap();
+        let target = match scopes
+            .iter_mut()
+            .rev()
+            .find(|s| s.contains_key(&identifier))
+        {
+            Some(scope) => scope.get_mut(&identifier),
+            None => self.symbols.get_mut(&identifier),
+        };
+        match target {
+            Some(target) => {
+                *target = value;
+                Ok(())
+            }
+            None => Err(InterpreterError::UnknownIdentifier(identifier)),
+        }
+    }
+
+    /// Processes the statements of a block in order, discarding their values.
+    fn process_block(&mut self, stmts: Vec<Stmt>) -> Result<(), RuntimeError> {
+        for stmt in stmts {
+            self.process_statement(stmt)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the modulus of the quotient ring, where a zero modulus leaves the quotient ring.
+    fn set_modulus(&mut self, modulus: Value<F>) -> Result<(), InterpreterError> {
+        let modulus = modulus.expect_poly()?;
+        if Self::poly_is_zero(&modulus) {
+            self.modulus = None;
+        } else if modulus.degree() == 0 {
+            return Err(InterpreterError::TypeError(format!(
+                "Expected a modulus of positive degree, found {}",
+                Self::poly_print(&modulus)
+            )));
+        } else {
+            self.modulus = Some(modulus);
+        }
+        Ok(())
+    }
+
+    /// Returns the modulus of the extension with the given degree, finding it when it is first
+    /// used.
+    pub(crate) fn extension_modulus(&mut self, degree: usize) -> &Polynomial<FieldElement<F>> {
+        self.extension_moduli
+            .entry(degree)
+            .or_insert_with(|| extension::modulus(degree))
+    }
+
+    /// Returns an error if the given length exceeds the maximum length of lists.
+    pub(crate) fn check_list_len(&self, len: u64) -> Result<(), InterpreterError> {
+        if len > self.max_list_len as u64 {
+            return Err(InterpreterError::ListLengthExceeded(self.max_list_len));
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the given degree exceeds the maximum degree.
+    pub(crate) fn check_degree(&self, degree: u64) -> Result<(), InterpreterError> {
+        if degree > self.max_degree as u64 {
+            return Err(InterpreterError::DegreeLimitExceeded(self.max_degree));
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the given degree exceeds the maximum degree for factoring.
+    pub(crate) fn check_factor_degree(&self, degree: usize) -> Result<(), InterpreterError> {
+        if degree > self.max_factor_degree {
+            return Err(InterpreterError::FactorDegreeExceeded(
+                self.max_factor_degree,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns an error if raising a polynomial of the given degree to the given exponent would
+    /// exceed the maximum degree.
+    fn check_pow_degree(
+        &self,
+        degree: usize,
+        exponent: &FieldElement<F>,
+    ) -> Result<(), InterpreterError> {
+        if degree == 0 {
+            return Ok(());
+        }
+
+        let degree = felt_to_u64(exponent).and_then(|exponent| exponent.checked_mul(degree as u64));
+        self.check_degree(degree.unwrap_or(u64::MAX))
+    }
+
+    /// Evaluates the variables of a polynomial at the given points.
+    ///
+    /// Since the variable of a univariate polynomial is unnamed, it can be evaluated with a
+    /// single binding of any name.
+    fn process_bindings(
+        value: Value<F>,
+        bindings: Vec<(String, FieldElement<F>)>,
+    ) -> Result<Value<F>, InterpreterError> {
+        match value {
+            Value::Poly(poly) => match bindings.as_slice() {
+                [(_, point)] => Ok(Self::felt_as_poly(poly.evaluate(point)).into()),
+                _ => Err(InterpreterError::TypeError(format!(
+                    "Expected a single binding for univariate polynomial {}",
+                    Self::poly_print(&poly)
+                ))),
+            },
+            Value::MultiPoly(mut poly) => {
+                for (var, point) in bindings {
+                    poly = poly
+                        .evaluate_var(&var, &point)
+                        .ok_or(InterpreterError::UnknownIdentifier(var))?;
+                }
+                Ok(poly.into())
+            }
+            value => Err(InterpreterError::TypeError(format!(
+                "Cannot evaluate {}",
+                value
+            ))),
+        }
+    }
+
+    /// Returns the KZG commitment scheme, making sure that the field is supported.
+    pub(crate) fn kzg(&mut self) -> Result<&mut Kzg, InterpreterError> {
+        if !is_kzg_field::<F>() {
+            return Err(InterpreterError::UnsupportedField(
+                "KZG commitments require the bls12-381 scalar field".to_string(),
+            ));
+        }
+
+        Ok(self.kzg.get_or_insert_with(|| Kzg::new(&self.srs_seed)))
+    }
+
+    /// Commits to the polynomial that the given expression evaluates to.
+    fn process_commit(&mut self, expr: Expr) -> Result<Value<F>, RuntimeError> {
+        let poly = self.process_expr(expr, &[])?.expect_poly()?;
+        let kzg = self.kzg()?; // check the field before converting to it
+        let poly = Polynomial::new(
+            &poly
+                .coefficients()
+                .iter()
+                .map(to_kzg_felt)
+                .collect::<Vec<_>>(),
+        );
+
+        Ok(Value::Commitment(kzg.commit(&poly)))
+    }
+
+    /// Commits to the polynomial that the given expression evaluates to with FRI, proving that
+    /// its degree is less than the given bound, or the next power of two above its degree if no
+    /// bound is given.
+    fn process_fri_commit(
+        &mut self,
+        expr: Expr,
+        degree_bound: Option<u64>,
+        blowup: Option<u64>,
+    ) -> Result<Value<F>, RuntimeError> {
+        let blowup = blowup.map_or(DEFAULT_FRI_BLOWUP, |blowup| blowup as usize);
+        if blowup < 2 || !blowup.is_power_of_two() {
+            return Err(InterpreterError::TypeError(format!(
+                "FRI blowup must be a power of two greater than 1, found {}",
+                blowup
+            ))
+            .into());
+        }
+
+        if degree_bound.is_some_and(|bound| !bound.is_power_of_two()) {
+            return Err(InterpreterError::TypeError(format!(
+                "FRI degree bound must be a power of two, found {}",
+                degree_bound.unwrap()
+            ))
+            .into());
+        }
+
+        let poly = self.process_expr(expr, &[])?.expect_poly()?;
+        let degree_bound = degree_bound
+            .map_or(poly.coeff_len().max(1).next_power_of_two(), |bound| {
+                bound as usize
+            });
+        let domain_size = fri::domain_size(&poly, degree_bound, blowup).unwrap_or(usize::MAX);
+        self.check_list_len(domain_size as u64)?;
+        let proof = FriProof::prove(&poly, degree_bound, blowup).ok_or_else(|| {
+            InterpreterError::UnsupportedField(format!(
+                "FRI domain of size {} has no root of unity in this field",
+                domain_size
+            ))
+        })?;
+        Ok(Value::Fri(proof))
+    }
+
+    /// Creates an opening proof for the given evaluation expression, e.g. `P@2`.
+    fn process_prove(&mut self, expr: Expr) -> Result<Value<F>, RuntimeError> {
+        let ExprKind::BinaryOp {
+            lhs,
+            op: BinaryOp::Evl,
+            rhs,
+        } = expr.kind
+        else {
+            return Err(RuntimeError::from(InterpreterError::TypeError(
+                "Expected an evaluation to prove, e.g. prove P@2".to_string(),
+            ))
+            .with_span(expr.span));
+        };
+
+        let poly = self.process_expr(*lhs, &[])?.expect_poly()?;
+        let z = self.process_expr(*rhs, &[])?.expect_felt()?;
+        let y = poly.evaluate(&z);
+        let kzg = self.kzg()?; // check the field before converting to it
+        let poly = Polynomial::new(
+            &poly
+                .coefficients()
+                .iter()
+                .map(to_kzg_felt)
+                .collect::<Vec<_>>(),
+        );
+
+        let proof = kzg.open(&to_kzg_felt(&z), &to_kzg_felt(&y), &poly);
+        Ok(Value::Proof(proof))
+    }
+
+    /// The value of last evaluated "expression statement" is stored at `!!` symbol for internal testing.
+    ///
+    /// Returns `None` for statements that do not result in a value, i.e. function definitions.
+    fn process_statement(&mut self, stmt: Stmt) -> Result<Option<Value<F>>, RuntimeError> {
+        let span = stmt.span;
+        self.process_statement_kind(stmt.kind)
+            .map_err(|err| err.with_span(span))
+    }
+
+    /// Processes a statement without its location, see [`Self::process_statement`].
+    fn process_statement_kind(&mut self, kind: StmtKind) -> Result<Option<Value<F>>, RuntimeError> {
+        match kind {
+            StmtKind::Let(identifier, expr) => {
+                let value = self.process_expr(expr, &[])?;
+                self.define(identifier, value.clone());
+                Ok(Some(value))
+            }
+            StmtKind::LetPoly(identifier, terms, expr) => {
+                let value = self.process_expr(expr, &terms)?;
+                self.define(identifier, value.clone());
+                Ok(Some(value))
+            }
+            StmtKind::Expr(expr) => {
+                let value = self.process_expr(expr, &[])?;
+                self.symbols.insert("!!".to_string(), value.clone());
+                Ok(Some(value))
+            }
+            StmtKind::Assert(expr) => {
+                let result = self.process_expr(expr, &[])?;
                 // fail if the result is zero, which means the assertion is false
                 // otherwise, return the result as is
-                if Self::poly_is_zero(&result) {
-                    Err(InterpreterError::AssertionFailed)
+                if result.is_zero() {
+                    Err(InterpreterError::AssertionFailed.into())
                 } else {
-                    Ok(result)
+                    Ok(Some(result))
+                }
+            }
+            StmtKind::Fn(identifier, params, body) => {
+                self.functions.insert(identifier, Function { params, body });
+                Ok(None)
+            }
+            StmtKind::Commit(identifier, expr) => {
+                let commitment = self.process_commit(expr)?;
+                let identifier = identifier.unwrap_or("!!".to_string());
+                self.define(identifier, commitment.clone());
+                Ok(Some(commitment))
+            }
+            StmtKind::FriCommit(identifier, expr, degree_bound, blowup) => {
+                let proof = self.process_fri_commit(expr, degree_bound, blowup)?;
+                let identifier = identifier.unwrap_or("!!".to_string());
+                self.define(identifier, proof.clone());
+                Ok(Some(proof))
+            }
+            StmtKind::Prove(identifier, expr) => {
+                let proof = self.process_prove(expr)?;
+                let identifier = identifier.unwrap_or("!!".to_string());
+                self.define(identifier, proof.clone());
+                Ok(Some(proof))
+            }
+            StmtKind::Assign(identifier, expr) => {
+                let value = self.process_expr(expr, &[])?;
+                self.assign(identifier, value.clone())?;
+                Ok(Some(value))
+            }
+            StmtKind::Modulus(expr) => {
+                let modulus = self.process_expr(expr, &[])?;
+                self.set_modulus(modulus.clone())?;
+                Ok(Some(modulus))
+            }
+            StmtKind::Block(stmts) => {
+                self.within_scope(HashMap::new(), |polenta| polenta.process_block(stmts))?;
+                Ok(None)
+            }
+            StmtKind::For(var, iter, body) => {
+                let items = self.process_expr(iter, &[])?.expect_list()?;
+                for item in items {
+                    self.count_iteration()?;
+                    // each iteration has its own scope, where the variable is bound to the item
+                    let scope = HashMap::from([(var.clone(), item)]);
+                    self.within_scope(scope, |polenta| polenta.process_block(body.clone()))?;
                 }
+                Ok(None)
             }
         }
     }
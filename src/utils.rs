@@ -6,25 +6,64 @@ use lambdaworks_math::{
     polynomial::Polynomial,
 };
 
-use crate::program::Polenta;
+use crate::{errors::InterpreterError, multivariate::MultiPolynomial, ntt, program::Polenta};
 
 /// Several utilities related to polynomials and field elements used within Polenta.
 pub trait PolentaUtilExt<F: IsField> {
-    /// Treats the given constants polynomial as a field element.
-    fn poly_as_felt(poly: &Polynomial<FieldElement<F>>) -> FieldElement<F>;
+    /// Treats the given constant polynomial as a field element, returning an error if the
+    /// polynomial is not constant.
+    fn poly_as_felt(
+        poly: &Polynomial<FieldElement<F>>,
+    ) -> Result<FieldElement<F>, InterpreterError>;
 
     /// Treats the given field element as a constant polynomial.
     fn felt_as_poly(felt: FieldElement<F>) -> Polynomial<FieldElement<F>>;
 
+    /// Prints a field element as the decimal form of its canonical representative.
+    fn felt_print(felt: &FieldElement<F>) -> String;
+
+    /// Returns the order of the field in decimal form.
+    fn field_order() -> String;
+
     /// Pretty-prints a given polynomial.
     fn poly_print(poly: &Polynomial<FieldElement<F>>) -> String;
 
-    /// Multiplies a polynomial with itself many times.
+    /// Pretty-prints a given multivariate polynomial.
+    fn multi_poly_print(poly: &MultiPolynomial<F>) -> String;
+
+    /// Raises a polynomial to the power of the canonical representative of the exponent.
     fn poly_pow(
         poly: &Polynomial<FieldElement<F>>,
         exponent: FieldElement<F>,
     ) -> Polynomial<FieldElement<F>>;
 
+    /// Raises a multivariate polynomial to the power of the canonical representative of the
+    /// exponent.
+    fn multi_poly_pow(poly: &MultiPolynomial<F>, exponent: FieldElement<F>) -> MultiPolynomial<F>;
+
+    /// Returns the composition `P(Q(x))` of the given polynomials.
+    fn poly_compose(
+        poly: &Polynomial<FieldElement<F>>,
+        inner: &Polynomial<FieldElement<F>>,
+    ) -> Polynomial<FieldElement<F>>;
+
+    /// Returns the monic greatest common divisor `g` of the given polynomials, along with the
+    /// Bézout coefficients `s` and `t` such that `s*a + t*b = g`.
+    ///
+    /// The greatest common divisor of two zero polynomials is zero.
+    #[allow(clippy::type_complexity)]
+    fn poly_xgcd(
+        a: &Polynomial<FieldElement<F>>,
+        b: &Polynomial<FieldElement<F>>,
+    ) -> (
+        Polynomial<FieldElement<F>>,
+        Polynomial<FieldElement<F>>,
+        Polynomial<FieldElement<F>>,
+    );
+
+    /// Returns the formal derivative of a polynomial.
+    fn poly_derivative(poly: &Polynomial<FieldElement<F>>) -> Polynomial<FieldElement<F>>;
+
     /// Returns true if the given polynomial is a zero polynomial.
     fn poly_is_zero(poly: &Polynomial<FieldElement<F>>) -> bool;
 
@@ -33,10 +72,74 @@ pub trait PolentaUtilExt<F: IsField> {
 }
 
 impl<F: IsPrimeField> PolentaUtilExt<F> for Polenta<F> {
-    fn poly_as_felt(poly: &Polynomial<FieldElement<F>>) -> FieldElement<F> {
+    fn poly_as_felt(
+        poly: &Polynomial<FieldElement<F>>,
+    ) -> Result<FieldElement<F>, InterpreterError> {
         // zero poly has len 0, and constant polys have len 1
-        assert!(poly.coeff_len() <= 1, "Expected a constant polynomial."); // TODO: return error
-        poly.leading_coefficient()
+        if poly.coeff_len() > 1 {
+            return Err(InterpreterError::ExpectedConstant {
+                found_degree: poly.degree(),
+            });
+        }
+        Ok(poly.leading_coefficient())
+    }
+
+    fn poly_compose(
+        poly: &Polynomial<FieldElement<F>>,
+        inner: &Polynomial<FieldElement<F>>,
+    ) -> Polynomial<FieldElement<F>> {
+        // Horner's method, where the point is a polynomial
+        poly.coefficients()
+            .iter()
+            .rev()
+            .fold(Polynomial::zero(), |result, coeff| {
+                result * inner + coeff.clone()
+            })
+    }
+
+    fn poly_xgcd(
+        a: &Polynomial<FieldElement<F>>,
+        b: &Polynomial<FieldElement<F>>,
+    ) -> (
+        Polynomial<FieldElement<F>>,
+        Polynomial<FieldElement<F>>,
+        Polynomial<FieldElement<F>>,
+    ) {
+        // extended Euclidean algorithm, keeping `s*a + t*b = r` for each remainder
+        let one = Polynomial::new_monomial(FieldElement::one(), 0);
+        let (mut r0, mut r1) = (a.clone(), b.clone());
+        let (mut s0, mut s1) = (one.clone(), Polynomial::zero());
+        let (mut t0, mut t1) = (Polynomial::zero(), one);
+        while !Self::poly_is_zero(&r1) {
+            let (q, r) = r0.long_division_with_remainder(&r1);
+            let s = s0 - &q * &s1;
+            let t = t0 - &q * &t1;
+            (r0, r1) = (r1, r);
+            (s0, s1) = (s1, s);
+            (t0, t1) = (t1, t);
+        }
+
+        // scale everything so that the gcd is monic
+        if Self::poly_is_zero(&r0) {
+            return (r0, s0, t0);
+        }
+        let lc_inv = r0
+            .leading_coefficient()
+            .inv()
+            .expect("expected a non-zero leading coefficient");
+        (&lc_inv * &r0, &lc_inv * &s0, &lc_inv * &t0)
+    }
+
+    fn poly_derivative(poly: &Polynomial<FieldElement<F>>) -> Polynomial<FieldElement<F>> {
+        // the coefficient of x^i becomes the coefficient of x^(i-1), multiplied by i
+        let coeffs = poly
+            .coefficients()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, coeff)| coeff * FieldElement::from(i as u64))
+            .collect::<Vec<_>>();
+        Polynomial::new(&coeffs)
     }
 
     fn poly_is_zero(poly: &Polynomial<FieldElement<F>>) -> bool {
@@ -55,26 +158,50 @@ impl<F: IsPrimeField> PolentaUtilExt<F> for Polenta<F> {
         Polynomial::new_monomial(felt, 0)
     }
 
+    fn felt_print(felt: &FieldElement<F>) -> String {
+        representative_to_decimal(&felt.representative().to_string())
+    }
+
+    fn field_order() -> String {
+        let modulus_minus_one = representative_to_decimal(&F::modulus_minus_one().to_string());
+        decimal_add_one(&modulus_minus_one)
+    }
+
     fn poly_print(poly: &Polynomial<FieldElement<F>>) -> String {
-        let coeff_decimals = poly
-            .coefficients()
-            .iter()
-            .map(|coeff| format!("{}", coeff.representative()))
-            .collect::<Vec<_>>();
+        poly_print_in(poly, "x")
+    }
 
-        let result = coeff_decimals
-            .iter()
-            .enumerate()
-            .rev()
-            .map(|(i, coeff)| match (i, coeff.as_str()) {
-                (_, "0") => String::new(),
-                (0, ___) => format!("{}", coeff),
-                (1, "1") => "x".to_string(),
-                (1, ___) => format!("{}*x", coeff),
-                (_, "1") => format!("x^{}", i),
-                (_, ___) => format!("{}*x^{}", coeff, i),
+    fn multi_poly_print(poly: &MultiPolynomial<F>) -> String {
+        // higher total degrees come first, ties are broken by the order of variables
+        let mut terms = poly.terms().collect::<Vec<_>>();
+        terms.sort_by_key(|(exponents, _)| {
+            (
+                std::cmp::Reverse(exponents.iter().sum::<usize>()),
+                std::cmp::Reverse(exponents.to_vec()),
+            )
+        });
+
+        let result = terms
+            .into_iter()
+            .map(|(exponents, coeff)| {
+                let monomial = poly
+                    .vars()
+                    .iter()
+                    .zip(exponents)
+                    .filter(|(_, exponent)| **exponent != 0)
+                    .map(|(var, exponent)| match exponent {
+                        1 => var.to_string(),
+                        _ => format!("{}^{}", var, exponent),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("*");
+
+                match (Self::felt_print(coeff).as_str(), monomial.as_str()) {
+                    (coeff, "") => coeff.to_string(),
+                    ("1", _) => monomial,
+                    (coeff, _) => format!("{}*{}", coeff, monomial),
+                }
             })
-            .filter(|s| !s.is_empty())
             .collect::<Vec<_>>()
             .join(" + ");
 
@@ -85,18 +212,228 @@ impl<F: IsPrimeField> PolentaUtilExt<F> for Polenta<F> {
         }
     }
 
+    fn multi_poly_pow(poly: &MultiPolynomial<F>, exponent: FieldElement<F>) -> MultiPolynomial<F> {
+        // constants are raised within the field
+        if let Some(constant) = poly.as_constant() {
+            return MultiPolynomial::new_constant(
+                poly.vars(),
+                constant.pow(exponent.representative()),
+            );
+        }
+
+        // square-and-multiply over the bits of the exponent
+        let (zero, one) = (
+            F::RepresentativeType::from(0),
+            F::RepresentativeType::from(1),
+        );
+        let mut exponent = exponent.representative();
+        let mut base = poly.clone();
+        let mut result = MultiPolynomial::new_constant(poly.vars(), FieldElement::one()); // 1
+        while exponent != zero {
+            if exponent & one == one {
+                result = result * base.clone();
+            }
+            exponent >>= 1;
+            if exponent != zero {
+                base = base.clone() * base;
+            }
+        }
+
+        result
+    }
+
     fn poly_pow(
         poly: &Polynomial<FieldElement<F>>,
-        mut exponent: FieldElement<F>,
+        exponent: FieldElement<F>,
     ) -> Polynomial<FieldElement<F>> {
-        // TODO: do the efficient square-and-multiply method
-        let (one, zero) = (FieldElement::from(1), FieldElement::from(0));
-        let mut result = Polynomial::new_monomial(one.clone(), 0); // 1
+        // constants are raised within the field
+        if poly.coeff_len() <= 1 {
+            return Self::felt_as_poly(poly.leading_coefficient().pow(exponent.representative()));
+        }
+
+        // square-and-multiply over the bits of the exponent
+        let (zero, one) = (
+            F::RepresentativeType::from(0),
+            F::RepresentativeType::from(1),
+        );
+        let mut exponent = exponent.representative();
+        let mut base = poly.clone();
+        let mut result = Polynomial::new_monomial(FieldElement::one(), 0); // 1
         while exponent != zero {
-            result = result * poly.clone();
-            exponent = exponent - one.clone();
+            if exponent & one == one {
+                result = ntt::mul(&result, &base);
+            }
+            exponent >>= 1;
+            if exponent != zero {
+                base = ntt::mul(&base, &base);
+            }
         }
 
         result
     }
 }
+
+/// Pretty-prints a given polynomial in terms of the given variable.
+pub(crate) fn poly_print_in<F: IsPrimeField>(
+    poly: &Polynomial<FieldElement<F>>,
+    var: &str,
+) -> String {
+    let coeff_decimals = poly
+        .coefficients()
+        .iter()
+        .map(Polenta::felt_print)
+        .collect::<Vec<_>>();
+
+    let result = coeff_decimals
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, coeff)| match (i, coeff.as_str()) {
+            (_, "0") => String::new(),
+            (0, _) => coeff.to_string(),
+            (1, "1") => var.to_string(),
+            (1, _) => format!("{}*{}", coeff, var),
+            (_, "1") => format!("{}^{}", var, i),
+            (_, _) => format!("{}*{}^{}", coeff, var, i),
+        })
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" + ");
+
+    if result.is_empty() {
+        "0".to_string()
+    } else {
+        result
+    }
+}
+
+/// Returns the canonical representative of a field element as an integer, if it fits in 64 bits.
+pub(crate) fn felt_to_u64<F: IsPrimeField>(felt: &FieldElement<F>) -> Option<u64> {
+    let (zero, one) = (
+        F::RepresentativeType::from(0),
+        F::RepresentativeType::from(1),
+    );
+    let mut repr = felt.representative();
+    let mut result = 0;
+    for i in 0..u64::BITS {
+        if repr == zero {
+            break;
+        }
+        if repr & one == one {
+            result |= 1 << i;
+        }
+        repr >>= 1;
+    }
+
+    (repr == zero).then_some(result)
+}
+
+/// Converts an integer literal to a field element by reducing it modulo the order of the field,
+/// and returns it along with whether the literal was reduced, i.e. whether it exceeds the order.
+///
+/// The literal is expected to be in decimal, or in hexadecimal or binary with a `0x` or `0b`
+/// prefix respectively, without any separators.
+pub(crate) fn literal_to_felt<F: IsPrimeField>(literal: &str) -> (FieldElement<F>, bool) {
+    let bits = literal_to_bits(literal);
+    let mut felt = FieldElement::<F>::zero();
+    for bit in bits.iter().rev() {
+        felt = &felt + &felt;
+        if *bit {
+            felt += FieldElement::<F>::one();
+        }
+    }
+
+    // the literal was reduced if and only if the representative has different bits
+    let (zero, one) = (
+        F::RepresentativeType::from(0),
+        F::RepresentativeType::from(1),
+    );
+    let mut repr = felt.representative();
+    let mut repr_bits = Vec::with_capacity(bits.len());
+    while repr != zero {
+        repr_bits.push(repr & one == one);
+        repr >>= 1;
+    }
+
+    (felt, repr_bits != bits)
+}
+
+/// Returns the bits of an integer literal in little-endian order, without trailing zeros.
+fn literal_to_bits(literal: &str) -> Vec<bool> {
+    let (digits, radix) = if let Some(hex) = literal.strip_prefix("0x") {
+        (hex, 16)
+    } else if let Some(bin) = literal.strip_prefix("0b") {
+        (bin, 2)
+    } else {
+        (literal, 10)
+    };
+
+    // little-endian limbs in base 2^32
+    let mut limbs: Vec<u64> = vec![];
+    for c in digits.chars() {
+        let mut carry = c.to_digit(radix).expect("expected a digit") as u64;
+        for limb in limbs.iter_mut() {
+            let value = *limb * radix as u64 + carry;
+            *limb = value & u32::MAX as u64;
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry);
+        }
+    }
+
+    let mut bits = limbs
+        .iter()
+        .flat_map(|limb| (0..32).map(move |i| (limb >> i) & 1 == 1))
+        .collect::<Vec<_>>();
+    while bits.last() == Some(&false) {
+        bits.pop();
+    }
+    bits
+}
+
+/// Converts the display form of a representative to decimal.
+///
+/// Small fields display their representatives as primitive integers in decimal form, while the
+/// big-integer backed ones display them in hexadecimal with a `0x` prefix.
+fn representative_to_decimal(repr: &str) -> String {
+    let Some(hex) = repr.strip_prefix("0x") else {
+        return repr.to_string();
+    };
+
+    // little-endian digits in base 10^9
+    const BASE: u64 = 1_000_000_000;
+    let mut digits: Vec<u64> = vec![0];
+    for c in hex.chars() {
+        let mut carry = c.to_digit(16).expect("expected a hex digit") as u64;
+        for digit in digits.iter_mut() {
+            let value = *digit * 16 + carry;
+            *digit = value % BASE;
+            carry = value / BASE;
+        }
+        if carry != 0 {
+            digits.push(carry);
+        }
+    }
+
+    let mut result = digits.last().unwrap().to_string();
+    for digit in digits.iter().rev().skip(1) {
+        result.push_str(&format!("{:09}", digit));
+    }
+    result
+}
+
+/// Adds one to a number given in decimal form.
+fn decimal_add_one(decimal: &str) -> String {
+    let mut digits = decimal.bytes().rev().map(|b| b - b'0').collect::<Vec<_>>();
+    for digit in digits.iter_mut() {
+        if *digit == 9 {
+            *digit = 0;
+        } else {
+            *digit += 1;
+            return digits.iter().rev().map(|d| (d + b'0') as char).collect();
+        }
+    }
+    digits.push(1);
+    digits.iter().rev().map(|d| (d + b'0') as char).collect()
+}
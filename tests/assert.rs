@@ -20,3 +20,8 @@ fn test_assert_let() {
 fn test_assert_inv() {
     run_test_for_assert("let x = 123; let y = 1 / 123; assert x * y == 1;");
 }
+
+#[test]
+fn test_keyword_prefixed_identifiers() {
+    run_test_for_assert("let assertion = 1; assertion = 2; assert assertion == 2;");
+}
@@ -0,0 +1,121 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField;
+use polenta::Polenta;
+
+/// Interprets the given input over the scalar field of BLS12-381, and returns the last value.
+fn run_kzg_test(input: &str) -> String {
+    Polenta::<FrField>::new()
+        .interpret(input)
+        .expect("should interpret")
+        .pop()
+        .expect("should have a value")
+        .to_string()
+}
+
+#[test]
+fn test_commit_and_verify() {
+    let result = run_kzg_test(
+        r#"
+        let P(x) = 3*x^2 + 2*x + 1;
+        let C = commit P;
+        let pi = prove P@2;
+        verify(C, 2, 17, pi);
+        "#,
+    );
+    assert_eq!(result, "1");
+}
+
+#[test]
+fn test_verify_wrong_evaluation() {
+    let result = run_kzg_test(
+        r#"
+        let P(x) = 3*x^2 + 2*x + 1;
+        let C = commit P;
+        let pi = prove P@2;
+        verify(C, 2, 18, pi);
+        "#,
+    );
+    assert_eq!(result, "0");
+
+    // proof for a different polynomial
+    let result = run_kzg_test(
+        r#"
+        let P(x) = 3*x^2 + 2*x + 1;
+        let Q(x) = x^3;
+        let C = commit P;
+        let pi = prove Q@2;
+        verify(C, 2, 8, pi);
+        "#,
+    );
+    assert_eq!(result, "0");
+}
+
+#[test]
+fn test_commit_is_deterministic() {
+    let input = "let P(x) = x^4 + 7; commit P;";
+    let result = run_kzg_test(input);
+    assert!(result.starts_with("commitment(0x"));
+    assert_eq!(result, run_kzg_test(input));
+
+    // the commitment depends on the seed
+    let other = Polenta::<FrField>::new()
+        .with_srs_seed("other")
+        .interpret(input)
+        .unwrap()
+        .pop()
+        .unwrap()
+        .to_string();
+    assert_ne!(result, other);
+
+    // equal polynomials have equal commitments
+    let result = run_kzg_test(
+        "let P(x) = 2*x + 1; let Q(x) = x + x + 1; let C = commit P; let D = commit Q; C == D;",
+    );
+    assert_eq!(result, "1");
+}
+
+#[test]
+fn test_keyword_prefixed_identifiers() {
+    run_test_for_symbols(
+        r#"
+        let commitment = 2;
+        let y = commitment;
+        let provenance = 3;
+        let z = provenance + 1;
+        "#,
+        vec![("y", "2"), ("z", "4")],
+    );
+}
+
+#[test]
+fn test_commitment_errors() {
+    let err = run_test_for_error("let P(x) = x; commit P;");
+    assert_eq!(
+        "Unsupported Field: KZG commitments require the bls12-381 scalar field".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let P(x) = x; prove P@2;");
+    assert_eq!(
+        "Unsupported Field: KZG commitments require the bls12-381 scalar field".to_string(),
+        err.to_string()
+    );
+
+    let err = Polenta::<FrField>::new()
+        .interpret("let P(x) = x; prove P;")
+        .unwrap_err();
+    assert_eq!(
+        "Type Error: Expected an evaluation to prove, e.g. prove P@2".to_string(),
+        err.to_string()
+    );
+
+    let err = Polenta::<FrField>::new()
+        .interpret("let P(x) = x; let C = commit P; verify(C, 1, 1);")
+        .unwrap_err();
+    assert_eq!(
+        "Function verify expects 4 arguments, found 3".to_string(),
+        err.to_string()
+    );
+}
@@ -7,10 +7,10 @@
 
 use std::collections::HashMap;
 
-use polenta::{Polenta, PolentaError, PolentaUtilExt};
+use polenta::{Polenta, PolentaError};
 type F = lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
 
-pub fn run_test_for_assert(input: &str) -> () {
+pub fn run_test_for_assert(input: &str) {
     Polenta::<F>::new()
         .interpret(input)
         .expect("should interpret");
@@ -18,7 +18,7 @@ pub fn run_test_for_assert(input: &str) -> () {
 
 pub fn run_test_for_error(input: &str) -> PolentaError {
     let result = Polenta::<F>::new().interpret(input);
-    return result.expect_err("should return error");
+    result.expect_err("should return error")
 }
 
 /// Runs tests over the Goldilocks field (no particular reason for the field choice).
@@ -28,7 +28,7 @@ pub fn run_test(input: &str) -> HashMap<String, String> {
     polenta
         .symbols
         .into_iter()
-        .map(|(k, v)| (k, Polenta::poly_print(&v)))
+        .map(|(k, v)| (k, v.to_string()))
         .collect()
 }
 
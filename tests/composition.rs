@@ -0,0 +1,55 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+#[test]
+fn test_compose() {
+    run_test_for_symbols(
+        r#"
+        let P(x) = x^2 + 1;
+        let Q(x) = 2*x + 3;
+        let PQ = P@Q;
+        let QP = Q@P;
+        let R = compose(P, Q);
+        let same = PQ == R;
+        "#,
+        vec![
+            ("PQ", "4*x^2 + 12*x + 10"),
+            ("QP", "2*x^2 + 5"),
+            ("same", "1"),
+        ],
+    );
+}
+
+#[test]
+fn test_compose_shifted() {
+    // shifting a vanishing polynomial, e.g. Z(g*x)
+    run_test_for_symbols(
+        r#"
+        let Z(x) = (x - 1) * (x - 2);
+        let g = 3;
+        let S(x) = Z@(g*x);
+        let a = S@(1/3);
+        let b = S@(2/3);
+        "#,
+        vec![
+            ("S", "9*x^2 + 18446744069414584312*x + 2"),
+            ("a", "0"),
+            ("b", "0"),
+        ],
+    );
+
+    // composing with constants is evaluation, and with x is the identity
+    run_test_for_symbols(
+        "let P(x) = x^3 + x; let a = P@2; let Q(x) = P@x; let c = compose(5, P);",
+        vec![("a", "10"), ("Q", "x^3 + x"), ("c", "5")],
+    );
+}
+
+#[test]
+fn test_compose_errors() {
+    let err = run_test_for_error("let P(x, y) = x * y; compose(P, 2);");
+    assert_eq!(
+        "Type Error: Expected a univariate polynomial, found x*y".to_string(),
+        err.to_string()
+    );
+}
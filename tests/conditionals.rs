@@ -0,0 +1,98 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+#[test]
+fn test_conditional() {
+    run_test_for_symbols(
+        r#"
+        let a = if 1 then 2 else 3;
+        let b = if 0 then 2 else 3;
+        let c = 1 + if 2 == 2 then 10 else 20;
+        let P(x) = if 1 then x^2 else x;
+        fn abs_diff(a, b) = if a < b then b - a else a - b;
+        let d = abs_diff(3, 5) + abs_diff(5, 3);
+        "#,
+        vec![
+            ("a", "2"),
+            ("b", "3"),
+            ("c", "11"),
+            ("P", "x^2"),
+            ("d", "4"),
+        ],
+    );
+}
+
+#[test]
+fn test_comparisons() {
+    run_test_for_symbols(
+        r#"
+        let lt = [1 < 2, 2 < 2, 3 < 2];
+        let le = [1 <= 2, 2 <= 2, 3 <= 2];
+        let gt = [1 > 2, 2 > 2, 3 > 2];
+        let ge = [1 >= 2, 2 >= 2, 3 >= 2];
+        let neg = -1 > 1000;
+        let prec = 1 + 1 < 3 == 1;
+        "#,
+        vec![
+            ("lt", "[1, 0, 0]"),
+            ("le", "[1, 1, 0]"),
+            ("gt", "[0, 0, 1]"),
+            ("ge", "[0, 1, 1]"),
+            // the canonical representative of -1 is p - 1
+            ("neg", "1"),
+            ("prec", "1"),
+        ],
+    );
+}
+
+#[test]
+fn test_logical() {
+    run_test_for_symbols(
+        r#"
+        let and = [1 && 1, 1 && 0, 0 && 1, 0 && 0];
+        let or = [1 || 1, 1 || 0, 0 || 1, 0 || 0];
+        let not = [!0, !5];
+        let prec = 0 && 1 || 1;
+        let P(x) = x;
+        let truthy = P && 7;
+        "#,
+        vec![
+            ("and", "[1, 0, 0, 0]"),
+            ("or", "[1, 1, 1, 0]"),
+            ("not", "[1, 0]"),
+            ("prec", "1"),
+            ("truthy", "1"),
+        ],
+    );
+}
+
+#[test]
+fn test_short_circuit() {
+    // the failing expressions are never evaluated
+    run_test_for_symbols(
+        r#"
+        let a = 0 && 1 / 0;
+        let b = 1 || unknown;
+        let c = if 1 then 5 else 1 / 0;
+        fn f(n) = if n == 0 then 1 else n * f(n - 1);
+        let d = f(5);
+        "#,
+        vec![("a", "0"), ("b", "1"), ("c", "5"), ("d", "120")],
+    );
+}
+
+#[test]
+fn test_conditional_errors() {
+    let err = run_test_for_error("let P(x) = x; P < 2;");
+    assert_eq!(
+        "Expected Constant: found a polynomial of degree 1".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("1 && 1 / 0;");
+    assert_eq!("Division by Zero".to_string(), err.to_string());
+
+    // keywords can not be used as identifiers
+    let err = run_test_for_error("let then = 1;");
+    assert_eq!("Syntax Error".to_string(), err.to_string());
+}
@@ -0,0 +1,68 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+#[test]
+fn test_deriv() {
+    run_test_for_symbols(
+        r#"
+        let P(x) = 3*x^3 + 2*x^2 + x + 7;
+        let D = deriv(P);
+        let D2 = deriv(P, 2);
+        let D4 = deriv(P, 4);
+        let D0 = deriv(P, 0);
+        let c = deriv(5);
+        "#,
+        vec![
+            ("D", "9*x^2 + 4*x + 1"),
+            ("D2", "18*x + 4"),
+            ("D4", "0"),
+            ("D0", "3*x^3 + 2*x^2 + x + 7"),
+            ("c", "0"),
+        ],
+    );
+}
+
+#[test]
+fn test_deriv_postfix() {
+    run_test_for_symbols(
+        r#"
+        let P(x) = x^4 + x;
+        let D = P';
+        let D2 = P'';
+        let a = P'@2;
+        let b = -P';
+        let Q(x) = (x^2 + 1)' * x;
+        "#,
+        vec![
+            ("D", "4*x^3 + 1"),
+            ("D2", "12*x^2"),
+            ("a", "33"),
+            ("b", "18446744069414584317*x^3 + 18446744069414584320"),
+            ("Q", "2*x^2"),
+        ],
+    );
+}
+
+#[test]
+fn test_deriv_multiplicity() {
+    // a double root is also a root of the derivative
+    run_test_for_symbols(
+        "let P(x) = (x - 3)^2 * (x + 1); let a = P@3; let b = P'@3; let c = P''@3;",
+        vec![("a", "0"), ("b", "0"), ("c", "8")],
+    );
+}
+
+#[test]
+fn test_deriv_errors() {
+    let err = run_test_for_error("let P(x, y) = x * y; P';");
+    assert_eq!(
+        "Type Error: Expected a univariate polynomial, found x*y".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("deriv(1, 2, 3);");
+    assert_eq!(
+        "Function deriv expects 2 arguments, found 3".to_string(),
+        err.to_string()
+    );
+}
@@ -20,3 +20,126 @@ fn test_syntax_error() {
     let err = run_test_for_error("let a = ++;");
     assert_eq!("Syntax Error".to_string(), err.to_string());
 }
+
+/// Returns the offset and length of the first label of an error.
+fn label_of(err: &polenta::PolentaError) -> (usize, usize) {
+    use miette::Diagnostic;
+    let label = err.labels().unwrap().next().unwrap();
+    (label.offset(), label.len())
+}
+
+#[test]
+fn test_error_spans() {
+    // innermost expression that failed
+    let err = run_test_for_error("let a = 1;\nlet b = a + c * 2;");
+    assert_eq!(label_of(&err), (23, 1));
+
+    let err = run_test_for_error("let a = 3 / (3 - 3);");
+    assert_eq!(label_of(&err), (8, 11));
+
+    // errors within functions are located at the call
+    let err = run_test_for_error("fn f(x) = x / 0; 3 + f(2);");
+    assert_eq!(label_of(&err), (21, 4));
+
+    // statements that fail as a whole
+    let err = run_test_for_error("let a = 1; assert a == 2;");
+    assert_eq!(label_of(&err), (11, 14));
+}
+
+#[test]
+fn test_error_help() {
+    use miette::Diagnostic;
+    let help_of = |input| {
+        run_test_for_error(input)
+            .help()
+            .map(|help| help.to_string())
+    };
+
+    assert_eq!(
+        help_of("assert 0;"),
+        Some("Asserted expression must be non-zero.".to_string())
+    );
+    assert_eq!(
+        help_of("fn f(a) = f(a); f(1);"),
+        Some(
+            "Nested function calls are limited in depth, see `Polenta::with_max_call_depth`."
+                .to_string()
+        )
+    );
+    assert_eq!(help_of("let a = b;"), None);
+
+    // the lookaheads for keywords are not listed as expected
+    assert_eq!(
+        help_of("let a = ;"),
+        Some("Expected one of [expr], got []".to_string())
+    );
+}
+
+#[test]
+fn test_syntax_error_location() {
+    use miette::Diagnostic;
+    type F = lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+
+    let input = "let a = 1;\nlet b = 2;\n\nlet c = ++;\n";
+    let err = polenta::Polenta::<F>::new()
+        .interpret_named("myfile.pol", input)
+        .unwrap_err();
+    assert_eq!(label_of(&err), (31, 1));
+
+    // the whole input is the source, under the given name
+    let contents = err
+        .source_code()
+        .unwrap()
+        .read_span(&(31, 1).into(), 0, 0)
+        .unwrap();
+    assert_eq!(contents.name(), Some("myfile.pol"));
+    assert_eq!((contents.line(), contents.column()), (3, 8));
+}
+
+#[test]
+fn test_expected_constant() {
+    let err = run_test_for_error("let P(x) = x + 1; let Q(x) = x^2; P^Q;");
+    assert_eq!(
+        "Expected Constant: found a polynomial of degree 2".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let P(x) = 3*x; let Q(x) = x^P;");
+    assert_eq!(
+        "Expected Constant: found a polynomial of degree 1".to_string(),
+        err.to_string()
+    );
+    assert_eq!(label_of(&err), (27, 3));
+
+    // bindings are located at the bound expression
+    let err = run_test_for_error("let P(x, y) = x * y; let Q(z) = z; P@(x=1, y=Q);");
+    assert_eq!(
+        "Expected Constant: found a polynomial of degree 1".to_string(),
+        err.to_string()
+    );
+    assert_eq!(label_of(&err), (45, 1));
+}
+
+#[test]
+fn test_degree_limit() {
+    // would take forever without a limit
+    let err = run_test_for_error("let P(x) = x^(0-1);");
+    assert_eq!(
+        format!("Maximum Degree Exceeded: {}", polenta::DEFAULT_MAX_DEGREE),
+        err.to_string()
+    );
+
+    type F = lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+    let mut polenta = polenta::Polenta::<F>::new().with_max_degree(10);
+    assert!(polenta
+        .interpret("let P(x) = x^10; let Q(x) = x^5 * x^5;")
+        .is_ok());
+    for input in [
+        "let P(x) = x^11;",
+        "let P(x) = x^6 * x^5;",
+        "let P(x, y) = (x*y)^6;",
+    ] {
+        let err = polenta.interpret(input).unwrap_err();
+        assert_eq!("Maximum Degree Exceeded: 10".to_string(), err.to_string());
+    }
+}
@@ -0,0 +1,142 @@
+pub mod common;
+use common::{run_test_for_assert, run_test_for_error, run_test_for_symbols};
+
+use lambdaworks_math::field::{
+    element::FieldElement,
+    extensions::quadratic::QuadraticExtensionFieldElement,
+    fields::{
+        fft_friendly::babybear::Babybear31PrimeField,
+        u64_goldilocks_field::{Goldilocks64ExtensionField, Goldilocks64Field},
+    },
+};
+use polenta::{Polenta, Value};
+
+#[test]
+fn test_ext_arithmetic() {
+    run_test_for_symbols(
+        r#"
+        let u = ext([0, 1]);
+        let z = 3 + 5*u;
+        let a = u^2;
+        let b = z * u;
+        let c = z - z;
+        let d = components(z);
+        "#,
+        vec![
+            ("u", "u"),
+            ("z", "5*u + 3"),
+            ("a", "7"),
+            ("b", "3*u + 35"),
+            ("c", "0"),
+            ("d", "[3, 5]"),
+        ],
+    );
+
+    run_test_for_assert(
+        r#"
+        let u = ext([0, 1]);
+        let z = ext([3, 5]);
+        assert z == 3 + 5*u;
+        assert (1 / z) * z == 1;
+        assert z^3 == z * z * z;
+        assert -z + z == 0;
+        assert ext([4, 0]) == 4;
+        "#,
+    );
+}
+
+#[test]
+fn test_ext_evaluation() {
+    run_test_for_assert(
+        r#"
+        let P(x) = x^2 + 1;
+        let z = ext([3, 5]);
+        assert P@z == z^2 + 1;
+        assert P@ext([2, 0]) == P@2;
+        "#,
+    );
+}
+
+#[test]
+fn test_ext_matches_lambdaworks() {
+    type FE = FieldElement<Goldilocks64Field>;
+    type Fee = FieldElement<Goldilocks64ExtensionField>;
+
+    let mut polenta = Polenta::<Goldilocks64Field>::new();
+    let results = polenta.interpret("1 / ext([3, 5]);").unwrap();
+    let Value::Ext(inverse) = &results[0] else {
+        panic!("expected an extension field element");
+    };
+
+    let expected = Fee::new([FE::from(3), FE::from(5)]).inv().unwrap();
+    assert_eq!(inverse.components(), expected.value().to_vec());
+}
+
+#[test]
+fn test_ext_moduli() {
+    type FE = FieldElement<Babybear31PrimeField>;
+    type Fee = QuadraticExtensionFieldElement<Babybear31PrimeField, Babybear31PrimeField>;
+
+    // lambdaworks uses u^2 = -1 over BabyBear, whereas the smallest binomial is u^2 - 11
+    let mut polenta = Polenta::<Babybear31PrimeField>::new();
+    let results = polenta
+        .interpret("let u = ext([0, 1]); u^2; ext([0, 1, 0])^3; ext([0, 1, 0, 0])^4;")
+        .unwrap();
+    let results = results.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+    assert_eq!(results[1..], ["11", "2", "11"]);
+
+    let Value::Ext(u) = &polenta.symbols["u"] else {
+        panic!("expected an extension field element");
+    };
+    assert_eq!(
+        u.modulus().coefficients(),
+        [-FE::from(11), FE::zero(), FE::one()]
+    );
+    assert_eq!(
+        Fee::new([FE::zero(), FE::one()]).square().value()[0],
+        -FE::one()
+    );
+}
+
+#[test]
+fn test_ext_errors() {
+    let err = run_test_for_error("ext([1]);");
+    assert_eq!(
+        "Type Error: Extension degree must be at least 2, found 1".to_string(),
+        err.to_string()
+    );
+
+    run_test_for_assert("let e = ext(range(8)); assert components(e) == range(8);");
+    let err = run_test_for_error("ext(range(64));");
+    assert_eq!(
+        "Type Error: Extension degree must be at most 8, found 64".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("ext([1, 2]) + ext([1, 2, 3]);");
+    assert_eq!(
+        "Type Error: Cannot mix elements of extensions of degrees 2 and 3".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("ext([1, 2]) % 2;");
+    assert_eq!(
+        "Type Error: Modulo is not defined for extension field elements".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("ext([1, 2]) < ext([2, 1]);");
+    assert_eq!(
+        "Type Error: Extension field elements cannot be compared".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("components(3);");
+    assert_eq!(
+        "Type Error: Expected an extension field element, found 3".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("1 / ext([0, 0]);");
+    assert_eq!("Division by Zero".to_string(), err.to_string());
+}
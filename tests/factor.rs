@@ -0,0 +1,156 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+use lambdaworks_math::field::fields::u64_prime_field::U64PrimeField;
+use polenta::{AnyPolenta, Polenta, PolentaField};
+
+#[test]
+fn test_roots() {
+    run_test_for_symbols(
+        r#"
+        let P(x) = 3 * (x - 1)^2 * (x + 2) * (x^2 - 7);
+        let R = roots(P);
+        let Q(x) = (x - 1) * (x - 2) * (x - 3) * (x - 4) * (x - 5);
+        let S = roots(Q);
+        let T(x) = x^2 - 7;
+        let U = roots(T);
+        let c = roots(5);
+        "#,
+        vec![
+            ("R", "[(1, 2), (18446744069414584319, 1)]"),
+            ("S", "[(1, 1), (2, 1), (3, 1), (4, 1), (5, 1)]"),
+            ("U", "[]"),
+            ("c", "[]"),
+        ],
+    );
+}
+
+#[test]
+fn test_factor() {
+    run_test_for_symbols(
+        r#"
+        let P(x) = 3 * (x - 1)^2 * (x + 2) * (x^2 - 7);
+        let F = factor(P);
+        let Q(x) = (x^3 - 7) * (x^3 - 49);
+        let G = factor(Q);
+        let c = factor(5);
+        "#,
+        vec![
+            (
+                "F",
+                "[(x + 2, 1), (x + 18446744069414584320, 2), (x^2 + 18446744069414584314, 1)]",
+            ),
+            (
+                "G",
+                "[(x^3 + 18446744069414584272, 1), (x^3 + 18446744069414584314, 1)]",
+            ),
+            ("c", "[]"),
+        ],
+    );
+}
+
+#[test]
+fn test_factor_errors() {
+    let err = run_test_for_error("roots(0);");
+    assert_eq!(
+        "Type Error: Expected a non-zero polynomial, found 0".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let P(x, y) = x * y; factor(P);");
+    assert_eq!(
+        "Type Error: Expected a univariate polynomial, found x*y".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("roots(vanishing(512));");
+    assert_eq!(
+        "Maximum Factoring Degree Exceeded: 64".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let P(x) = x^65 + 1; factor(P);");
+    assert_eq!(
+        "Maximum Factoring Degree Exceeded: 64".to_string(),
+        err.to_string()
+    );
+}
+
+#[test]
+fn test_factor_degree_limit() {
+    type F = lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+    let mut polenta = Polenta::<F>::new().with_max_factor_degree(4);
+    assert!(polenta.interpret("roots(vanishing(4));").is_ok());
+    assert!(polenta.interpret("roots(vanishing(8));").is_err());
+}
+
+#[test]
+fn test_factor_fields() {
+    for field in [
+        PolentaField::BabyBear,
+        PolentaField::Mersenne31,
+        PolentaField::Bls12381,
+    ] {
+        let mut polenta = AnyPolenta::new(field);
+        let results = polenta
+            .interpret(
+                "let P(x) = (x - 3)^2 * (x - 5) * (x^2 + x + 1); let Q(x) = x + 1; roots(P@Q);",
+            )
+            .unwrap();
+        // x^2 + x + 1 has roots exactly when the field has cube roots of unity
+        assert!(
+            results[2].starts_with("[(2, 2), (4, 1)"),
+            "{}: {}",
+            field.name(),
+            results[2]
+        );
+    }
+}
+
+#[test]
+fn test_factor_small_fields() {
+    // exponents are reduced within the field, so x^p is written as x^(p-1) * x
+    let mut polenta = Polenta::<U64PrimeField<7>>::new();
+    let results = polenta
+        .interpret(
+            r#"
+            let X(x) = x^6 * x;
+            let P(x) = X - x;
+            roots(P);
+            let Q(x) = (x - 1)^6 * x^2;
+            factor(Q);
+            "#,
+        )
+        .unwrap();
+    // every element is a root of x^p - x
+    assert_eq!(
+        results[2].to_string(),
+        "[(0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1)]"
+    );
+    // multiplicities below the order of the field are kept as they are
+    assert_eq!(results[4].to_string(), "[(x, 2), (x + 6, 6)]");
+
+    // (x - 1)^7 = x^7 - 1 is a polynomial in x^7, whose multiplicity would be reduced to zero
+    for input in ["factor(X - 1);", "roots(X - 1);"] {
+        let err = polenta.interpret(input).unwrap_err();
+        assert_eq!(
+            "Unsupported Field: Multiplicity 7 is not less than the order of the field".to_string(),
+            err.to_string()
+        );
+    }
+
+    // both irreducible cubics over the binary field
+    let mut polenta = Polenta::<U64PrimeField<2>>::new();
+    let results = polenta
+        .interpret(
+            r#"
+            let X(x) = x * x * x;
+            let P(x) = (X + x + 1) * (X + x*x + 1) * (x*x + x + 1);
+            factor(P);
+            "#,
+        )
+        .unwrap();
+    assert_eq!(
+        results[2].to_string(),
+        "[(x^2 + x + 1, 1), (x^3 + x + 1, 1), (x^3 + x^2 + 1, 1)]"
+    );
+}
@@ -0,0 +1,101 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+use polenta::{AnyPolenta, PolentaField};
+
+#[test]
+fn test_domain() {
+    run_test_for_symbols(
+        "let D1 = domain(1); let D2 = domain(2); let D4 = domain(4);",
+        vec![
+            ("D1", "[1]"),
+            ("D2", "[1, 18446744069414584320]"),
+            (
+                "D4",
+                "[1, 281474976710656, 18446744069414584320, 18446462594437873665]",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn test_fft() {
+    run_test_for_symbols(
+        r#"
+        let P(x) = 3*x^3 + 2*x + 1;
+        let E = fft(P, 2);
+        let Q = ifft(fft(P, 8));
+        let same = P == Q;
+        let R = ifft(fft(P, 2));
+        let c = ifft([5, 5, 5, 5]);
+        "#,
+        vec![
+            ("E", "[6, 18446744069414584317]"),
+            ("same", "1"),
+            // x^2 is 1 over a domain of size 2
+            ("R", "5*x + 1"),
+            ("c", "5"),
+        ],
+    );
+}
+
+#[test]
+fn test_ntt_mul() {
+    // large enough to be multiplied with the NTT
+    run_test_for_symbols(
+        r#"
+        let P(x) = (x + 1)^100 + x;
+        let Q(x) = (x - 2)^90 + 3;
+        let a = (P * Q)@5 == (P@5) * (Q@5);
+        let R(x) = (x + 1)^100;
+        let b = R@2 == 3^100;
+        "#,
+        vec![("a", "1"), ("b", "1")],
+    );
+
+    // Mersenne31 has no large enough domain, so it falls back to the schoolbook method
+    let mut polenta = AnyPolenta::new(PolentaField::Mersenne31);
+    let results = polenta
+        .interpret("let P(x) = (x + 1)^100; let Q(x) = (x - 1)^100; (P * Q)@2 == 3^100;")
+        .unwrap();
+    assert_eq!(results[2], "1");
+}
+
+#[test]
+fn test_fft_errors() {
+    let err = run_test_for_error("domain(3);");
+    assert_eq!(
+        "Type Error: Domain size must be a power of two, found 3".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("ifft([]);");
+    assert_eq!(
+        "Type Error: Domain size must be a power of two, found 0".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let P(x) = x; fft(P, 0 - 1);");
+    assert_eq!(
+        format!(
+            "Maximum List Length Exceeded: {}",
+            polenta::DEFAULT_MAX_LIST_LEN
+        ),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("domain(2^24);");
+    assert_eq!(
+        format!(
+            "Maximum List Length Exceeded: {}",
+            polenta::DEFAULT_MAX_LIST_LEN
+        ),
+        err.to_string()
+    );
+
+    let mut polenta = AnyPolenta::new(PolentaField::Mersenne31);
+    let err = polenta.interpret("domain(4);").unwrap_err();
+    assert_eq!(
+        "Unsupported Field: Domain of size 4 has no root of unity in this field".to_string(),
+        err.to_string()
+    );
+}
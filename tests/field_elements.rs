@@ -0,0 +1,142 @@
+pub mod common;
+use common::{run_test_for_assert, run_test_for_error, run_test_for_symbols};
+
+use lambdaworks_math::field::fields::u64_prime_field::U64PrimeField;
+use polenta::{AnyPolenta, Polenta, PolentaField};
+
+#[test]
+fn test_inv() {
+    run_test_for_symbols(
+        "let a = inv(2); let b = a * 2; let c = inv(0 - 1);",
+        vec![
+            ("a", "9223372034707292161"),
+            ("b", "1"),
+            ("c", "18446744069414584320"),
+        ],
+    );
+
+    let err = run_test_for_error("inv(0);");
+    assert_eq!("Division by Zero".to_string(), err.to_string());
+}
+
+#[test]
+fn test_sqrt_and_legendre() {
+    run_test_for_symbols(
+        r#"
+        let s = sqrt(4);
+        let z = sqrt(0);
+        let a = legendre(4);
+        let b = legendre(0);
+        let c = legendre(7);
+        "#,
+        vec![
+            ("s", "(2, 18446744069414584319)"),
+            ("z", "(0, 0)"),
+            ("a", "1"),
+            ("b", "0"),
+            ("c", "18446744069414584320"),
+        ],
+    );
+
+    run_test_for_assert(
+        r#"
+        for a in range(100) {
+            let s = sqrt(a * a);
+            assert s[0]^2 == a * a && s[0] + s[1] == 0;
+        }
+        "#,
+    );
+
+    let err = run_test_for_error("sqrt(7);");
+    assert_eq!("No Square Root: 7".to_string(), err.to_string());
+}
+
+#[test]
+fn test_sqrt_in_small_fields() {
+    let results = Polenta::<U64PrimeField<7>>::new()
+        .interpret("sqrt(2); sqrt(4); legendre(3);")
+        .unwrap();
+    let results = results.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+    assert_eq!(results, vec!["(3, 4)", "(2, 5)", "6"]);
+
+    // lambdaworks treats zero as a square and finds no non-residue when p = 2
+    let results = Polenta::<U64PrimeField<2>>::new()
+        .interpret("sqrt(1); legendre(1); generator(); sqrt(0); legendre(0);")
+        .unwrap();
+    let results = results.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+    assert_eq!(results, vec!["(1, 1)", "1", "1", "(0, 0)", "0"]);
+}
+
+#[test]
+fn test_order_and_generator() {
+    run_test_for_symbols(
+        r#"
+        let g = generator();
+        let a = order(g);
+        let b = order(1);
+        let c = order(0 - 1);
+        let d = order(g^(3 * 5));
+        "#,
+        vec![
+            ("g", "7"),
+            ("a", "18446744069414584320"),
+            ("b", "1"),
+            ("c", "2"),
+            ("d", "1229782937960972288"),
+        ],
+    );
+
+    let results = Polenta::<U64PrimeField<7>>::new()
+        .interpret("generator(); order(2); order(3);")
+        .unwrap();
+    let results = results.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+    assert_eq!(results, vec!["3", "3", "6"]);
+
+    let err = run_test_for_error("order(0);");
+    assert_eq!(
+        "Type Error: Expected a non-zero element, found 0".to_string(),
+        err.to_string()
+    );
+}
+
+#[test]
+fn test_root_of_unity() {
+    run_test_for_assert(
+        r#"
+        assert root_of_unity(4) == domain(4)[1];
+        assert root_of_unity(1) == 1;
+        assert order(root_of_unity(2^32)) == 2^32;
+        assert order(root_of_unity(3)) == 3;
+        assert order(root_of_unity(255)) == 255;
+        "#,
+    );
+
+    let err = run_test_for_error("root_of_unity(7);");
+    assert_eq!(
+        "Unsupported Field: No root of unity of order 7 in this field".to_string(),
+        err.to_string()
+    );
+}
+
+#[test]
+fn test_big_fields() {
+    let mut polenta = AnyPolenta::new(PolentaField::Bls12381);
+    let results = polenta
+        .interpret("let s = sqrt(9)[0]; s^2; legendre(9); root_of_unity(2^32)^(2^31);")
+        .unwrap();
+    assert_eq!(
+        results[1..],
+        [
+            "9",
+            "1",
+            "52435875175126190479447740508185965837690552500527637822603658699938581184512"
+        ]
+    );
+
+    let err = polenta.interpret("order(2);").unwrap_err();
+    assert_eq!(
+        "Unsupported Field: Multiplicative orders are only supported for fields of at most 64 bits"
+            .to_string(),
+        err.to_string()
+    );
+}
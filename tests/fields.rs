@@ -0,0 +1,52 @@
+use polenta::{AnyPolenta, PolentaField};
+
+#[test]
+fn test_field_names() {
+    for field in PolentaField::ALL {
+        assert_eq!(field.name().parse::<PolentaField>().unwrap(), field);
+    }
+    assert_eq!(
+        "BLS12381".parse::<PolentaField>().unwrap(),
+        PolentaField::Bls12381
+    );
+}
+
+#[test]
+fn test_unknown_field() {
+    let err = AnyPolenta::from_field_name("foobar").err().unwrap();
+    assert_eq!("Unknown Field: foobar".to_string(), err.to_string());
+}
+
+#[test]
+fn test_field_orders() {
+    let cases = [
+        (PolentaField::Goldilocks, "18446744069414584321"),
+        (PolentaField::Mersenne31, "2147483647"),
+        (PolentaField::BabyBear, "2013265921"),
+        (
+            PolentaField::Bls12381,
+            "52435875175126190479447740508185965837690552500527637822603658699938581184513",
+        ),
+    ];
+
+    for (field, order) in cases {
+        assert_eq!(AnyPolenta::new(field).order(), order);
+    }
+}
+
+#[test]
+fn test_field_arithmetic() {
+    let mut polenta = AnyPolenta::from_field_name("babybear").unwrap();
+    let results = polenta
+        .interpret("let a = -1; let P(x) = a*x + 2^31;")
+        .unwrap();
+    assert_eq!(results, vec!["2013265920", "2013265920*x + 134217727"]);
+    assert_eq!(polenta.symbol("a").unwrap(), "2013265920");
+
+    let mut polenta = AnyPolenta::new(PolentaField::Stark252);
+    let results = polenta.interpret("1 / 2;").unwrap();
+    assert_eq!(
+        results,
+        vec!["1809251394333065606848661391547535052811553607665798349986546028067936010241"]
+    );
+}
@@ -0,0 +1,127 @@
+pub mod common;
+use common::{run_test, run_test_for_error, run_test_for_symbols};
+
+use lambdaworks_math::field::fields::mersenne31::field::Mersenne31Field;
+use polenta::Polenta;
+
+#[test]
+fn test_fri_commit_and_verify() {
+    let symbols = run_test(
+        r#"
+        let P(x) = 3*x^5 + 2*x^2 + x + 7;
+        let C = fri_commit P with blowup 4;
+        let ok = fri_verify(C);
+        "#,
+    );
+    assert_eq!(symbols.get("ok").unwrap(), "1");
+    let commitment = symbols.get("C").unwrap();
+    assert!(commitment.starts_with("fri(0x"));
+    assert!(commitment.ends_with("degree < 8, blowup 4)"));
+}
+
+#[test]
+fn test_fri_various_degrees() {
+    for poly in ["5", "x", "x^2 + 1", "x^7 - 3*x^3", "x^16 + x^15 + 2"] {
+        let symbols = run_test(&format!(
+            "let P(x) = {}; let C = fri_commit P with blowup 2; let ok = fri_verify(C);",
+            poly
+        ));
+        assert_eq!(symbols.get("ok").unwrap(), "1", "failed for {}", poly);
+    }
+
+    // default blowup
+    let symbols = run_test("let P(x) = x^3; let C = fri_commit P; let ok = fri_verify(C);");
+    assert!(symbols.get("C").unwrap().ends_with("blowup 4)"));
+    assert_eq!(symbols.get("ok").unwrap(), "1");
+}
+
+#[test]
+fn test_fri_degree_bound() {
+    for (poly, bound, ok) in [
+        ("x^3 + 1", 8, "1"),
+        ("x^7 - 3*x^3", 8, "1"),
+        ("5", 1, "1"),
+        ("x^8", 8, "0"),
+        ("x^5 + x", 4, "0"),
+        ("x", 1, "0"),
+        // larger than the evaluation domain for the bound alone
+        ("x^32 + 1", 8, "0"),
+    ] {
+        let symbols = run_test(&format!(
+            "let P(x) = {}; let C = fri_commit P with degree < {}; let ok = fri_verify(C);",
+            poly, bound
+        ));
+        assert_eq!(symbols.get("ok").unwrap(), ok, "failed for {}", poly);
+    }
+
+    let symbols = run_test(
+        "let P(x) = x^9; let C = fri_commit P with degree < 16, blowup 2; let ok = fri_verify(C);",
+    );
+    assert!(symbols
+        .get("C")
+        .unwrap()
+        .ends_with("degree < 16, blowup 2)"));
+    assert_eq!(symbols.get("ok").unwrap(), "1");
+}
+
+#[test]
+fn test_fri_is_deterministic() {
+    let symbols = run_test(
+        r#"
+        let P(x) = 2*x^3 + 1;
+        let Q(x) = x^3 + x^3 + 1;
+        let R(x) = 2*x^3 + 2;
+        let C = fri_commit P;
+        let D = fri_commit Q;
+        let E = fri_commit R;
+        let same = C == D;
+        let different = C == E;
+        "#,
+    );
+    assert_eq!(symbols.get("same").unwrap(), "1");
+    assert_eq!(symbols.get("different").unwrap(), "0");
+}
+
+#[test]
+fn test_keyword_prefixed_identifiers() {
+    run_test_for_symbols(
+        "let fri_commitments = 2; let y = fri_commitments;",
+        vec![("y", "2")],
+    );
+}
+
+#[test]
+fn test_fri_errors() {
+    let err = run_test_for_error("let P(x) = x; fri_commit P with blowup 3;");
+    assert_eq!(
+        "Type Error: FRI blowup must be a power of two greater than 1, found 3".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let P(x) = x; fri_commit P with degree < 6;");
+    assert_eq!(
+        "Type Error: FRI degree bound must be a power of two, found 6".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let P(x) = x; fri_commit P with degree < 0x8000_0000;");
+    assert_eq!(
+        "Maximum List Length Exceeded: 65536".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("fri_verify(5);");
+    assert_eq!(
+        "Type Error: Expected a FRI proof, found 5".to_string(),
+        err.to_string()
+    );
+
+    // Mersenne31 has a two-adicity of 1
+    let err = Polenta::<Mersenne31Field>::new()
+        .interpret("let P(x) = x^2; fri_commit P;")
+        .unwrap_err();
+    assert_eq!(
+        "Unsupported Field: FRI domain of size 16 has no root of unity in this field".to_string(),
+        err.to_string()
+    );
+}
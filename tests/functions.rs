@@ -0,0 +1,73 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+#[test]
+fn test_fn_call() {
+    run_test_for_symbols(
+        r#"
+        fn sq(a) = a * a;
+        fn add(a, b) = a + b;
+        let z = add(sq(3), 1);
+        "#,
+        vec![("z", "10")],
+    );
+}
+
+#[test]
+fn test_fn_with_polys() {
+    run_test_for_symbols(
+        r#"
+        fn shift(P, c) = P + c;
+        let P(x) = 3*x;
+        let Q(x) = shift(P, 2) * shift(x, 1);
+        "#,
+        vec![("Q", "3*x^2 + 5*x + 2")],
+    );
+}
+
+#[test]
+fn test_fn_local_scope() {
+    run_test_for_symbols(
+        r#"
+        let a = 5;
+        let b = 7;
+        fn f(a) = a + b; // `a` is local, `b` is global
+        let c = f(1);
+        "#,
+        vec![("a", "5"), ("c", "8")],
+    );
+
+    // locals of a caller are not visible to the callee
+    let err = run_test_for_error("fn f(a) = g(1); fn g(b) = a + b; f(1);");
+    assert_eq!("Unknown Identifier: a".to_string(), err.to_string());
+}
+
+#[test]
+fn test_fn_errors() {
+    let err = run_test_for_error("f(1);");
+    assert_eq!("Unknown Function: f".to_string(), err.to_string());
+
+    let err = run_test_for_error("fn f(a, b) = a; f(1);");
+    assert_eq!(
+        "Function f expects 2 arguments, found 1".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("fn f(a) = f(a); f(1);");
+    assert_eq!(
+        "Maximum Call Depth Exceeded: 64".to_string(),
+        err.to_string()
+    );
+}
+
+#[test]
+fn test_keyword_prefixed_identifiers() {
+    run_test_for_symbols(
+        "let fnord = 2; fnord = fnord + 1; fn fnx(a) = a + fnord; let y = fnx(3);",
+        vec![("fnord", "3"), ("y", "6")],
+    );
+
+    // `fnx` is not read as `fn x`
+    let err = run_test_for_error("fnx(a) = a + 1; x(3);");
+    assert_eq!("Syntax Error".to_string(), err.to_string());
+}
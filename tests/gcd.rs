@@ -0,0 +1,82 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+#[test]
+fn test_gcd() {
+    run_test_for_symbols(
+        r#"
+        let P(x) = (x - 1) * (x - 2);
+        let Q(x) = 3 * (x - 1) * (x + 3);
+        let G = gcd(P, Q);
+        let R(x) = x^2 + 1;
+        let S(x) = x;
+        let coprime = gcd(R, S);
+        let Z = gcd(0, 0);
+        let T(x) = 2*x + 4;
+        let H = gcd(T, 0);
+        "#,
+        vec![
+            ("G", "x + 18446744069414584320"),
+            ("coprime", "1"),
+            ("Z", "0"),
+            ("H", "x + 2"),
+        ],
+    );
+}
+
+#[test]
+fn test_square_free() {
+    // a repeated root is shared with the derivative
+    run_test_for_symbols(
+        r#"
+        let P(x) = (x - 3)^2 * (x + 1);
+        let Q(x) = (x - 3) * (x + 1);
+        let a = gcd(P, P');
+        let b = gcd(Q, Q');
+        "#,
+        vec![("a", "x + 18446744069414584318"), ("b", "1")],
+    );
+}
+
+#[test]
+fn test_xgcd() {
+    run_test_for_symbols(
+        "let P(x) = x; let Q(x) = x + 1; let r = xgcd(P, Q); let s = xgcd(2*P, 4);",
+        vec![
+            ("r", "(1, 18446744069414584320, 1)"),
+            ("s", "(1, 0, 13835058052060938241)"),
+        ],
+    );
+}
+
+#[test]
+fn test_invmod() {
+    run_test_for_symbols(
+        r#"
+        let M(x) = x^2 + 1;
+        let X(x) = x;
+        let I = invmod(X, M);
+        let one = (X * I) % M;
+        let P(x) = x^3 + 2*x + 5;
+        let J = invmod(P, M);
+        let also_one = (P * J) % M;
+        "#,
+        vec![
+            ("I", "18446744069414584320*x"),
+            ("one", "1"),
+            ("also_one", "1"),
+        ],
+    );
+}
+
+#[test]
+fn test_invmod_errors() {
+    let err = run_test_for_error("let P(x) = x - 1; let M(x) = x^2 - 1; invmod(P, M);");
+    assert_eq!(
+        "Not Invertible: x + 18446744069414584320 modulo x^2 + 18446744069414584320".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let P(x) = x; invmod(P, 0);");
+    assert_eq!("Division by Zero".to_string(), err.to_string());
+}
@@ -0,0 +1,56 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+#[test]
+fn test_lists_and_tuples() {
+    run_test_for_symbols(
+        "let a = [1, 2 + 3, (4, 5)]; let b = []; let c = a == [1, 5, (4, 5)];",
+        vec![("a", "[1, 5, (4, 5)]"), ("b", "[]"), ("c", "1")],
+    );
+
+    let err = run_test_for_error("let a = [1, 2] + 1;");
+    assert_eq!(
+        "Type Error: Expected a polynomial, found [1, 2]".to_string(),
+        err.to_string()
+    );
+}
+
+#[test]
+fn test_interpolate() {
+    run_test_for_symbols(
+        r#"
+        let P = interpolate([(0, 1), (1, 3), (2, 7)]);
+        let Q = interpolate([(5, 9)]);
+        let Z = interpolate([]);
+        "#,
+        vec![("P", "x^2 + x + 1"), ("Q", "9"), ("Z", "0")],
+    );
+
+    // interpolating the evaluations of a polynomial results in the same polynomial
+    run_test_for_symbols(
+        r#"
+        let P(x) = 3*x^3 + 2*x + 7;
+        let Q = interpolate([(1, P@1), (2, P@2), (3, P@3), (4, P@4)]);
+        let same = P == Q;
+        "#,
+        vec![("same", "1")],
+    );
+}
+
+#[test]
+fn test_interpolate_errors() {
+    let err = run_test_for_error("interpolate([(1, 2), (3, 4), (1, 5)]);");
+    assert_eq!("Duplicate X-Coordinate: 1".to_string(), err.to_string());
+
+    let err = run_test_for_error("interpolate([(1, 2, 3)]);");
+    assert_eq!(
+        "Type Error: Expected a tuple with 2 elements, found (1, 2, 3)".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("interpolate((1, 2));");
+    assert_eq!(
+        "Type Error: Expected a list, found (1, 2)".to_string(),
+        err.to_string()
+    );
+}
@@ -0,0 +1,103 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+#[test]
+fn test_indexing() {
+    run_test_for_symbols(
+        r#"
+        let v = [1, 2, 3];
+        let a = v[0];
+        let b = v[1 + 1];
+        let t = (4, 5)[1];
+        let n = [[1, 2], [3, 4]][1][0];
+        let P(x) = x; let Q(x) = x + 1;
+        let s = xgcd(P, Q)[1];
+        "#,
+        vec![
+            ("a", "1"),
+            ("b", "3"),
+            ("t", "5"),
+            ("n", "3"),
+            ("s", "18446744069414584320"),
+        ],
+    );
+}
+
+#[test]
+fn test_len_and_range() {
+    run_test_for_symbols(
+        "let n = len([1, 2, 3]); let m = len((1, 2)); let r = range(4); let e = range(0);",
+        vec![("n", "3"), ("m", "2"), ("r", "[0, 1, 2, 3]"), ("e", "[]")],
+    );
+}
+
+#[test]
+fn test_comprehension() {
+    run_test_for_symbols(
+        r#"
+        let squares = [i^2 for i in range(4)];
+        let P(x) = x^2 + 1;
+        let evals = [P@w for w in domain(4)];
+        let same = evals == fft(P, 4);
+        let pairs = [[i + j for j in range(2)] for i in range(2)];
+        fn sq_all(v) = [a * a for a in v];
+        let sq = sq_all([1, 2, 3]);
+        "#,
+        vec![
+            ("squares", "[0, 1, 4, 9]"),
+            ("same", "1"),
+            ("pairs", "[[0, 1], [1, 2]]"),
+            ("sq", "[1, 4, 9]"),
+        ],
+    );
+}
+
+#[test]
+fn test_element_wise() {
+    run_test_for_symbols(
+        r#"
+        let a = [1, 2, 3] + [4, 5, 6];
+        let b = [1, 2, 3] * [4, 5, 6];
+        let c = [[1], [2]] - [[1], [1]];
+        let P(x) = x + 1; let Q(x) = x - 1;
+        let R = ifft(fft(P, 4) * fft(Q, 4));
+        "#,
+        vec![
+            ("a", "[5, 7, 9]"),
+            ("b", "[4, 10, 18]"),
+            ("c", "[[0], [1]]"),
+            ("R", "x^2 + 18446744069414584320"),
+        ],
+    );
+}
+
+#[test]
+fn test_list_errors() {
+    let err = run_test_for_error("[1, 2] + [1, 2, 3];");
+    assert_eq!(
+        "Type Error: Expected lists of equal length, found lengths 2 and 3".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let v = [1, 2, 3]; v[3];");
+    assert_eq!(
+        "Index Out of Bounds: 3 for a list of length 3".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("5[0];");
+    assert_eq!("Type Error: Cannot index 5".to_string(), err.to_string());
+
+    // the variable of a comprehension is not visible outside of it
+    let err = run_test_for_error("let v = [i for i in range(2)]; i;");
+    assert_eq!("Unknown Identifier: i".to_string(), err.to_string());
+
+    let err = run_test_for_error("range(0 - 1);");
+    assert_eq!(
+        format!(
+            "Maximum List Length Exceeded: {}",
+            polenta::DEFAULT_MAX_LIST_LEN
+        ),
+        err.to_string()
+    );
+}
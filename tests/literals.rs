@@ -0,0 +1,80 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+use polenta::{AnyPolenta, LiteralPolicy, Polenta, PolentaField};
+
+#[test]
+fn test_literal_formats() {
+    run_test_for_symbols(
+        "let a = 0xff; let b = 0b1010; let c = 1_000_000; let d = 0xFF_FF; let e = 007;",
+        vec![
+            ("a", "255"),
+            ("b", "10"),
+            ("c", "1000000"),
+            ("d", "65535"),
+            ("e", "7"),
+        ],
+    );
+}
+
+#[test]
+fn test_big_literals() {
+    // literals are reduced modulo the order of the field by default
+    run_test_for_symbols(
+        r#"
+        let a = 18446744069414584320;
+        let b = 18446744069414584321;
+        let c = 18446744073709551616;
+        let d = 0x1_0000_0000_0000_0000_0000_0000;
+        "#,
+        vec![
+            ("a", "18446744069414584320"),
+            ("b", "0"),
+            ("c", "4294967295"),
+            ("d", "18446744069414584320"),
+        ],
+    );
+
+    let mut polenta = AnyPolenta::new(PolentaField::Bls12381);
+    let order = polenta.order();
+    let results = polenta
+        .interpret(&format!(
+            "{order} - 1; {order}; 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000;"
+        ))
+        .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            "52435875175126190479447740508185965837690552500527637822603658699938581184512",
+            "0",
+            "52435875175126190479447740508185965837690552500527637822603658699938581184512",
+        ]
+    );
+}
+
+#[test]
+fn test_literal_policy() {
+    type F = lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+    let mut polenta = Polenta::<F>::new().with_literal_policy(LiteralPolicy::Reject);
+    assert!(polenta.interpret("let a = 18446744069414584320;").is_ok());
+
+    for (input, literal) in [
+        ("let a = 18446744069414584321;", "18446744069414584321"),
+        (
+            "let P(x) = x + 0xffff_ffff_ffff_ffff;",
+            "0xffffffffffffffff",
+        ),
+    ] {
+        let err = polenta.interpret(input).unwrap_err();
+        assert_eq!(format!("Literal Out of Range: {literal}"), err.to_string());
+    }
+}
+
+#[test]
+fn test_big_blowup() {
+    let err = run_test_for_error("let P(x) = x; fri_commit P with blowup 100000000000000000000;");
+    assert_eq!(
+        "Type Error: FRI blowup must be a power of two greater than 1, found 18446744073709551615",
+        err.to_string()
+    );
+}
@@ -0,0 +1,148 @@
+pub mod common;
+use common::{run_test, run_test_for_assert, run_test_for_error, run_test_for_symbols};
+
+use lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+use polenta::Polenta;
+
+#[test]
+fn test_for_loop() {
+    run_test_for_symbols(
+        r#"
+        let sum = 0;
+        for i in range(5) { sum = sum + i; }
+        let prod = 1;
+        for a in [2, 3, 4] {
+            let b = a * a;
+            prod = prod * b;
+        }
+        "#,
+        vec![("sum", "10"), ("prod", "576")],
+    );
+}
+
+#[test]
+fn test_repl_input() {
+    // the REPL terminates each line with `;`, including the lines that end with a block
+    let mut polenta = Polenta::<Goldilocks64Field>::new();
+    for line in [
+        "let Z(x) = 1;",
+        "for g in domain(4) { let L(x) = x - g; Z = Z * L; };",
+        "{ let a = 2; Z = Z * a; };",
+    ] {
+        polenta.interpret(line).unwrap();
+    }
+    let results = polenta.interpret("Z == 2 * vanishing(4);").unwrap();
+    assert_eq!(results[0].to_string(), "1");
+}
+
+#[test]
+fn test_vanishing_product() {
+    run_test_for_assert(
+        r#"
+        let Z(x) = 1;
+        for g in domain(4) {
+            let L(x) = x - g;
+            Z = Z * L;
+        }
+        assert Z == vanishing(4);
+        "#,
+    );
+}
+
+#[test]
+fn test_scopes() {
+    let symbols = run_test(
+        r#"
+        let a = 1;
+        { let a = 2; let b = a; a = 3; }
+        for i in range(2) { let c = i; }
+        let n = 0;
+        for i in range(3) { for j in range(2) { n = n + 1; } }
+        "#,
+    );
+
+    // definitions within blocks are local, assignments update the innermost definition
+    assert_eq!(symbols["a"], "1");
+    assert_eq!(symbols["n"], "6");
+    for identifier in ["b", "c", "i", "j"] {
+        assert!(!symbols.contains_key(identifier));
+    }
+
+    let err = run_test_for_error("{ let a = 1; } a;");
+    assert_eq!("Unknown Identifier: a".to_string(), err.to_string());
+    let err = run_test_for_error("a = 1;");
+    assert_eq!("Unknown Identifier: a".to_string(), err.to_string());
+}
+
+#[test]
+fn test_recursion_limit() {
+    // stays within the call depth, but would take 2^25 calls
+    let mut polenta = Polenta::<Goldilocks64Field>::new().with_max_iterations(1000);
+    let err = polenta
+        .interpret("fn f(n) = if n == 0 then 0 else f(n-1) + f(n-1); f(25);")
+        .unwrap_err();
+    assert_eq!(
+        "Maximum Iterations Exceeded: 1000".to_string(),
+        err.to_string()
+    );
+
+    // the budget is shared by calls and loops
+    let mut polenta = Polenta::<Goldilocks64Field>::new().with_max_iterations(10);
+    assert!(polenta
+        .interpret("fn f(n) = n; for i in range(5) { f(i); }")
+        .is_ok());
+    let err = polenta
+        .interpret("fn f(n) = n; for i in range(6) { f(i); }")
+        .unwrap_err();
+    assert_eq!(
+        "Maximum Iterations Exceeded: 10".to_string(),
+        err.to_string()
+    );
+}
+
+#[test]
+fn test_iteration_limit() {
+    type F = lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+    let mut polenta = polenta::Polenta::<F>::new().with_max_iterations(10);
+    assert!(polenta
+        .interpret("for i in range(5) { for j in range(1) { i; } }")
+        .is_ok());
+
+    // the budget is shared by loops and comprehensions, and is reset for each input
+    for input in [
+        "for i in range(11) { i; }",
+        "for i in range(4) { for j in range(2) { j; } }",
+        "let v = [i for i in range(6)]; for i in v { i; }",
+    ] {
+        let err = polenta.interpret(input).unwrap_err();
+        assert_eq!(
+            "Maximum Iterations Exceeded: 10".to_string(),
+            err.to_string()
+        );
+    }
+}
+
+#[test]
+fn test_keyword_prefixed_identifiers() {
+    run_test_for_symbols(
+        r#"
+        let letter = 1;
+        letter = 5;
+        let format = 2;
+        format = format + letter;
+        let inner = 0;
+        for index in range(3) { inner = inner + index; }
+        let L = [forty * 2 for forty in [1, 2]];
+        "#,
+        vec![
+            ("letter", "5"),
+            ("format", "7"),
+            ("inner", "3"),
+            ("L", "[2, 4]"),
+        ],
+    );
+
+    // `letX(x)` is not read as `let X(x)`
+    let err = run_test_for_error("letX(x) = x;");
+    assert_eq!("Syntax Error".to_string(), err.to_string());
+}
@@ -0,0 +1,77 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+#[test]
+fn test_multivariate_poly() {
+    run_test_for_symbols(
+        "let P(x, y, z) = 3*x^2*y + x*z + 5 + x*z;",
+        vec![("P", "3*x^2*y + 2*x*z + 5")],
+    );
+    run_test_for_symbols("let P(x, y) = (x + y)^2;", vec![("P", "x^2 + 2*x*y + y^2")]);
+}
+
+#[test]
+fn test_multivariate_arithmetic() {
+    run_test_for_symbols(
+        r#"
+        let P(x, y) = x + y;
+        let Q(x, y) = x - y;
+        let R(y, z) = y*z;
+        let A = P * Q / 2;
+        let B = P + R;
+        "#,
+        vec![
+            ("A", "9223372034707292161*x^2 + 9223372034707292160*y^2"),
+            ("B", "y*z + x + y"),
+        ],
+    );
+}
+
+#[test]
+fn test_multivariate_partial_eval() {
+    run_test_for_symbols(
+        r#"
+        let P(x, y, z) = x*y + y*z + 1;
+        let Q = P@(x=2);
+        let R = P@(x=2, z=3);
+        let a = P@(x=1, y=2, z=3);
+        let b = R@2;
+        "#,
+        vec![
+            ("Q", "y*z + 2*y + 1"),
+            ("R", "5*y + 1"),
+            ("a", "9"),
+            ("b", "11"),
+        ],
+    );
+}
+
+#[test]
+fn test_multivariate_equality() {
+    run_test_for_symbols(
+        r#"
+        let P(x, y) = x*y + 1;
+        let Q(y, x) = 1 + y*x;
+        let e = P == Q;
+        "#,
+        vec![("e", "1")],
+    );
+}
+
+#[test]
+fn test_multivariate_errors() {
+    let err = run_test_for_error("let P(x, y) = x*y; P@(z=2);");
+    assert_eq!("Unknown Identifier: z".to_string(), err.to_string());
+
+    let err = run_test_for_error("let P(x, y) = x*y; P@2;");
+    assert_eq!(
+        "Type Error: Cannot evaluate x*y at a single point, use bindings e.g. P@(x=1)".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let P(x) = x; let Q(x, y) = x*y; P + Q;");
+    assert_eq!(
+        "Type Error: Cannot mix univariate polynomial x with multivariate polynomials".to_string(),
+        err.to_string()
+    );
+}
@@ -1,5 +1,5 @@
 pub mod common;
-use common::run_test_for_symbols;
+use common::{run_test_for_error, run_test_for_symbols};
 
 #[test]
 fn test_poly_powers() {
@@ -39,6 +39,39 @@ fn test_multiplications() {
     );
 }
 
+#[test]
+fn test_divisions() {
+    run_test_for_symbols(
+        r#"
+        let P(x) = (x + 1)*(x + 2);
+        let Q(x) = x + 1;
+        let a = P / Q;
+        let b = (P + 3) div Q;
+        let c = divmod(P + 3, Q);
+        let D(x) = 3*x div 2;
+        "#,
+        vec![
+            ("a", "x + 2"),
+            ("b", "x + 2"),
+            ("c", "(x + 2, 3)"),
+            ("D", "9223372034707292162*x"),
+        ],
+    );
+
+    let err = run_test_for_error("let P(x) = x^2; let Q(x) = x + 1; P / Q;");
+    assert_eq!("Inexact Division: remainder 1".to_string(), err.to_string());
+
+    // `div` is a keyword, unlike `divmod`
+    let err = run_test_for_error("let div = 7;");
+    assert_eq!("Syntax Error".to_string(), err.to_string());
+
+    let err = run_test_for_error("let P(x) = x^2; P div 0;");
+    assert_eq!("Division by Zero".to_string(), err.to_string());
+
+    let err = run_test_for_error("let P(x) = x^2; divmod(P, 0);");
+    assert_eq!("Division by Zero".to_string(), err.to_string());
+}
+
 #[test]
 fn test_poly_eval() {
     run_test_for_symbols(
@@ -74,3 +107,18 @@ fn test_poly_with_identifier() {
         vec![("t", "2"), ("x", "5"), ("P", "x^2 + 2*x")],
     );
 }
+
+#[test]
+fn test_large_powers() {
+    // constants are raised within the field, e.g. Fermat's little theorem
+    run_test_for_symbols(
+        "let a = 3^(0-1); let b = 2^64; let c = 0^0;",
+        vec![("a", "1"), ("b", "4294967295"), ("c", "1")],
+    );
+
+    // square-and-multiply for polynomials
+    run_test_for_symbols(
+        "let P(x) = (x+1)^5; let Q(x) = x^1000; let d = Q@2 == 2^1000;",
+        vec![("P", "x^5 + 5*x^4 + 10*x^3 + 10*x^2 + 5*x + 1"), ("d", "1")],
+    );
+}
@@ -0,0 +1,82 @@
+pub mod common;
+use common::{run_test_for_assert, run_test_for_error, run_test_for_symbols};
+
+#[test]
+fn test_modulus() {
+    run_test_for_symbols(
+        r#"
+        let M(x) = x^4 - 1;
+        let X(x) = x;
+        modulus M;
+        let a = X^4;
+        let b = X^5 + X;
+        let P(x) = x^6 + 2;
+        let c = X^(2^60 + 1);
+        let d = [X^i for i in range(6)];
+        modulus 0;
+        let e = X^5;
+        "#,
+        vec![
+            ("a", "1"),
+            ("b", "2*x"),
+            ("P", "x^2 + 2"),
+            ("c", "x"),
+            ("d", "[1, x, x^2, x^3, 1, x]"),
+            ("e", "x^5"),
+        ],
+    );
+}
+
+#[test]
+fn test_keyword_prefixed_identifiers() {
+    // `modulusX` is an identifier, rather than a modulus statement for `X`
+    let err = run_test_for_error("let X(x) = x; modulusX;");
+    assert_eq!("Unknown Identifier: modulusX".to_string(), err.to_string());
+
+    run_test_for_symbols(
+        "let X(x) = x; let modulusX = X; let a = X^2;",
+        vec![("modulusX", "x"), ("a", "x^2")],
+    );
+}
+
+#[test]
+fn test_modulus_division() {
+    run_test_for_assert(
+        r#"
+        let M(x) = x^3 - x - 1;
+        let X(x) = x;
+        modulus M;
+        let I = 1 / (X^2 + 2);
+        assert I * (X^2 + 2) == 1;
+        assert I == invmod(X^2 + 2, M);
+        assert (X + 1) / X == 1 + 1 / X;
+        assert X / 2 == X * inv(2);
+        assert X div (X + 1) == X / (X + 1);
+        assert X^2 div X == X;
+        "#,
+    );
+}
+
+#[test]
+fn test_modulus_errors() {
+    let err = run_test_for_error("let M(x) = x^2 - 1; let P(x) = x - 1; modulus M; 1 / P;");
+    assert_eq!(
+        "Not Invertible: x + 18446744069414584320 modulo x^2 + 18446744069414584320".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let M(x) = x^2 + 1; modulus M; 1 / 0;");
+    assert_eq!("Division by Zero".to_string(), err.to_string());
+
+    let err = run_test_for_error("modulus 3;");
+    assert_eq!(
+        "Type Error: Expected a modulus of positive degree, found 3".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("let M(x, y) = x*y + 1; modulus M;");
+    assert_eq!(
+        "Type Error: Expected a univariate polynomial, found x*y + 1".to_string(),
+        err.to_string()
+    );
+}
@@ -0,0 +1,72 @@
+use polenta::AnyPolenta;
+
+/// Replays the REPL sessions of the README, so that the examples do not drift from the interpreter.
+///
+/// Each `rs` code block is a session on its own, where lines starting with `> ` are inputs and the
+/// lines up to the next input are the printed output. Only the last line of a successful output is
+/// checked, as that is what the REPL prints, and outputs of failing inputs are not checked. Long
+/// values such as hashes may be elided with `...` in the README.
+#[test]
+fn test_readme_examples() {
+    let readme = include_str!("../README.md");
+    for block in readme.split("```rs\n").skip(1) {
+        let block = block.split("```").next().unwrap();
+        let mut polenta = AnyPolenta::default();
+        let mut lines = block.lines().peekable();
+        while let Some(line) = lines.next() {
+            let Some(input) = line.strip_prefix("> ") else {
+                continue;
+            };
+            let mut output = Vec::new();
+            while let Some(next) = lines.next_if(|next| !next.starts_with("> ")) {
+                output.push(next);
+            }
+
+            // switching fields is a command of the REPL rather than a statement
+            if let Some(name) = input.strip_prefix("field ") {
+                polenta = AnyPolenta::from_field_name(name).unwrap();
+                continue;
+            }
+
+            match polenta.interpret(&format!("{};", input)) {
+                Ok(results) => {
+                    if let (Some(expected), Some(actual)) = (output.last(), results.last()) {
+                        assert!(
+                            matches_elided(expected, actual),
+                            "README input: {}\nexpected: {}\n  actual: {}",
+                            input,
+                            expected,
+                            actual
+                        );
+                    }
+                }
+                Err(err) => eprintln!("README input: {}\n{}", input, err),
+            }
+        }
+    }
+}
+
+/// Checks whether `actual` matches `expected`, where each `...` in `expected` stands for any text.
+fn matches_elided(expected: &str, actual: &str) -> bool {
+    let parts: Vec<&str> = expected.split("...").collect();
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if parts.len() == 1 {
+        return expected == actual;
+    }
+    if actual.len() < first.len() + last.len()
+        || !actual.starts_with(first)
+        || !actual.ends_with(last)
+    {
+        return false;
+    }
+
+    // the parts in between must appear in order
+    let mut rest = &actual[first.len()..actual.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
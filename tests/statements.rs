@@ -44,3 +44,39 @@ fn test_let_2() {
 fn expr_many_terminators() {
     run_test_for_symbols("let x = 22;;;;;;;", vec![("x", "22")]);
 }
+
+#[test]
+fn test_run_script() {
+    type F = lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+    let script = r#"
+        let P(x) = 3*x + 1;
+        P@2;
+        fn sq(a) = a * a;
+        let a = 5;
+        assert a == 5;
+        sq(a);
+    "#;
+
+    // only the values of expression statements are returned
+    let results = polenta::Polenta::<F>::new()
+        .run("script.pol", script)
+        .unwrap();
+    let results = results.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(results, vec!["7", "25"]);
+}
+
+#[test]
+fn test_run_empty_script() {
+    type F = lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+    for script in [
+        "",
+        "\n  \n",
+        "// nothing to see here\n",
+        "/// a doc\n// and a comment",
+    ] {
+        let results = polenta::Polenta::<F>::new()
+            .run("script.pol", script)
+            .unwrap();
+        assert!(results.is_empty());
+    }
+}
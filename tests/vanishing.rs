@@ -0,0 +1,63 @@
+pub mod common;
+use common::{run_test_for_error, run_test_for_symbols};
+
+#[test]
+fn test_vanishing() {
+    run_test_for_symbols(
+        r#"
+        let Z = vanishing(4);
+        let zeros = fft(Z, 4);
+        let V = vanishing([1, 2, 3]);
+        let one = vanishing([]);
+        "#,
+        vec![
+            ("Z", "x^4 + 18446744069414584320"),
+            ("zeros", "[0, 0, 0, 0]"),
+            (
+                "V",
+                "x^3 + 18446744069414584315*x^2 + 11*x + 18446744069414584315",
+            ),
+            ("one", "1"),
+        ],
+    );
+}
+
+#[test]
+fn test_lagrange_basis() {
+    run_test_for_symbols(
+        r#"
+        let L = lagrange_basis(1, [0, 1, 2]);
+        let sum = lagrange_basis(0, [0, 1, 2]) + L + lagrange_basis(2, [0, 1, 2]);
+        let E = fft(lagrange_basis(1, domain(4)), 4);
+        "#,
+        vec![
+            ("L", "18446744069414584320*x^2 + 2*x"),
+            ("sum", "1"),
+            ("E", "[0, 1, 0, 0]"),
+        ],
+    );
+}
+
+#[test]
+fn test_vanishing_errors() {
+    let err = run_test_for_error("lagrange_basis(3, [0, 1, 2]);");
+    assert_eq!(
+        "Index Out of Bounds: 3 for a list of length 3".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("lagrange_basis(0, [0, 1, 1]);");
+    assert_eq!("Duplicate X-Coordinate: 1".to_string(), err.to_string());
+
+    let err = run_test_for_error("vanishing(3);");
+    assert_eq!(
+        "Type Error: Domain size must be a power of two, found 3".to_string(),
+        err.to_string()
+    );
+
+    let err = run_test_for_error("vanishing(2^15);");
+    assert_eq!(
+        format!("Maximum Degree Exceeded: {}", polenta::DEFAULT_MAX_DEGREE),
+        err.to_string()
+    );
+}